@@ -0,0 +1,57 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::format;
+
+use crypto::ElementHasher;
+use fri::{FoldAndBatchProof, FriProof};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// LOW-DEGREE PROOF
+// ================================================================================================
+/// Low-degree proof for a DEEP composition polynomial.
+///
+/// A [Proof](super::Proof) carries one of two kinds of low-degree proof, depending on which
+/// prover produced it: `Monolithic` for the standard, single-instance FRI prover, and
+/// `FoldAndBatch` for the distributed Fold-and-Batch prover. Both variants flow through
+/// [Proof::to_bytes](super::Proof::to_bytes)/[Proof::from_bytes](super::Proof::from_bytes) and
+/// the standard verifier without a separate transport path.
+#[derive(Clone)]
+pub enum LowDegreeProof<H: ElementHasher> {
+    /// A low-degree proof produced by the standard, single-instance FRI prover.
+    Monolithic(FriProof),
+    /// A low-degree proof produced by the distributed Fold-and-Batch prover.
+    FoldAndBatch(FoldAndBatchProof<H>),
+}
+
+impl<H: ElementHasher> Serializable for LowDegreeProof<H> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            LowDegreeProof::Monolithic(fri_proof) => {
+                target.write_u8(0);
+                fri_proof.write_into(target);
+            }
+            LowDegreeProof::FoldAndBatch(fold_and_batch_proof) => {
+                target.write_u8(1);
+                fold_and_batch_proof.write_into(target);
+            }
+        }
+    }
+}
+
+impl<H: ElementHasher> Deserializable for LowDegreeProof<H> {
+    /// # Errors
+    /// Returns an error if the leading mode discriminant is neither 0 (monolithic) nor 1
+    /// (Fold-and-Batch), or if the proof of the indicated kind could not be read.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(LowDegreeProof::Monolithic(FriProof::read_from(source)?)),
+            1 => Ok(LowDegreeProof::FoldAndBatch(FoldAndBatchProof::read_from(source)?)),
+            mode => Err(DeserializationError::InvalidValue(format!(
+                "invalid low-degree proof mode discriminant: {mode}"
+            ))),
+        }
+    }
+}
@@ -7,7 +7,7 @@
 
 use alloc::vec::Vec;
 
-use crypto::{Hasher, MerkleTree};
+use crypto::{ElementHasher, Hasher, MerkleTree};
 use fri::FriProof;
 use math::FieldElement;
 use security::{ConjecturedSecurity, ProvenSecurity};
@@ -27,6 +27,9 @@ pub use queries::Queries;
 mod ood_frame;
 pub use ood_frame::{OodFrame, TraceOodFrame};
 
+mod low_degree_proof;
+pub use low_degree_proof::LowDegreeProof;
+
 mod security;
 
 mod table;
@@ -48,8 +51,14 @@ mod tests;
 ///
 /// To estimate soundness of a proof (in bits), [security_level()](Proof::security_level) function
 /// can be used.
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct Proof {
+///
+/// The low-degree proof it carries ([LowDegreeProof]) may have been produced either by the
+/// standard, single-instance FRI prover or by the distributed Fold-and-Batch prover; both flow
+/// through [to_bytes()](Proof::to_bytes)/[from_bytes()](Proof::from_bytes) and the standard
+/// verifier without a separate transport path. This is why `Proof` is generic over the hasher
+/// `H`, which a hasher-free monolithic proof did not otherwise need.
+#[derive(Clone)]
+pub struct Proof<H: ElementHasher> {
     /// Basic metadata about the execution of the computation described by this proof.
     pub context: Context,
     /// Number of unique queries made by the verifier. This will be different from the
@@ -66,12 +75,12 @@ pub struct Proof {
     /// Trace and constraint polynomial evaluations at an out-of-domain point.
     pub ood_frame: OodFrame,
     /// Low-degree proof for a DEEP composition polynomial.
-    pub fri_proof: FriProof,
+    pub low_degree_proof: LowDegreeProof<H>,
     /// Proof-of-work nonce for query seed grinding.
     pub pow_nonce: u64,
 }
 
-impl Proof {
+impl<H: ElementHasher> Proof<H> {
     /// Returns STARK protocol parameters used to generate this proof.
     pub fn options(&self) -> &ProofOptions {
         self.context.options()
@@ -121,6 +130,44 @@ impl Proof {
         )
     }
 
+    /// Returns security level (in bits) of a proof produced via the distributed Fold-and-Batch
+    /// prover, using conjectured security.
+    ///
+    /// Unlike [proven_security](Self::proven_security), conjectured security is not sensitive to
+    /// the number of codewords carried through the protocol, so the distributed protocol's
+    /// worker-local folding rounds and master batching step conjecture the same security as the
+    /// monolithic, single-instance case.
+    pub fn fold_and_batch_conjectured_security<H: Hasher>(&self) -> ConjecturedSecurity {
+        self.conjectured_security::<H>()
+    }
+
+    /// Returns security level (in bits) of a proof produced via the distributed Fold-and-Batch
+    /// prover, using proven security.
+    ///
+    /// The distributed protocol runs two proximity-gap-checked phases instead of one: each of the
+    /// `num_workers` worker nodes locally folds its own polynomial for `worker_folding_layers`
+    /// layers before the master batches the `num_batched_codewords` resulting worker last-layer
+    /// codewords into a single FRI instance. Every worker-local folding layer and every batched
+    /// codeword independently contributes its own proximity-gap soundness error (as in algebraic
+    /// batching), so — unlike [proven_security](Self::proven_security), which counts only the
+    /// codewords of a single monolithic instance — the combined error here is modeled by counting
+    /// the codewords of both phases together.
+    pub fn fold_and_batch_proven_security<H: Hasher>(
+        &self,
+        num_workers: usize,
+        worker_folding_layers: usize,
+        num_batched_codewords: usize,
+    ) -> ProvenSecurity {
+        let total_number_of_polys = num_batched_codewords + num_workers * worker_folding_layers;
+        ProvenSecurity::compute(
+            self.context.options(),
+            self.context.num_modulus_bits(),
+            self.trace_info().length(),
+            H::COLLISION_RESISTANCE,
+            total_number_of_polys,
+        )
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -157,7 +204,7 @@ impl Proof {
                 vec![vec![DummyField::ONE]],
             ),
             ood_frame: OodFrame::default(),
-            fri_proof: FriProof::new_dummy(),
+            low_degree_proof: LowDegreeProof::Monolithic(FriProof::new_dummy()),
             pow_nonce: 0,
         }
     }
@@ -166,7 +213,7 @@ impl Proof {
 // SERIALIZATION
 // ================================================================================================
 
-impl Serializable for Proof {
+impl<H: ElementHasher> Serializable for Proof<H> {
     fn write_into<W: utils::ByteWriter>(&self, target: &mut W) {
         self.context.write_into(target);
         target.write_u8(self.num_unique_queries);
@@ -174,12 +221,12 @@ impl Serializable for Proof {
         target.write_many(&self.trace_queries);
         self.constraint_queries.write_into(target);
         self.ood_frame.write_into(target);
-        self.fri_proof.write_into(target);
+        self.low_degree_proof.write_into(target);
         self.pow_nonce.write_into(target);
     }
 }
 
-impl Deserializable for Proof {
+impl<H: ElementHasher> Deserializable for Proof<H> {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let context = Context::read_from(source)?;
         let num_unique_queries = source.read_u8()?;
@@ -197,7 +244,7 @@ impl Deserializable for Proof {
             trace_queries,
             constraint_queries: Queries::read_from(source)?,
             ood_frame: OodFrame::read_from(source)?,
-            fri_proof: FriProof::read_from(source)?,
+            low_degree_proof: LowDegreeProof::read_from(source)?,
             pow_nonce: source.read_u64()?,
         };
         Ok(proof)
@@ -29,10 +29,11 @@ pub fn fold_and_batch_worker(c: &mut Criterion) {
 
             let worker_domain_size = worker_degree_bound * BLOWUP_FACTOR;
             let options = FoldingOptions::new(
-                BLOWUP_FACTOR, 
-                FOLDING_FACTOR, 
-                worker_domain_size, 
-                last_poly_max_degree);
+                BLOWUP_FACTOR,
+                FOLDING_FACTOR,
+                worker_domain_size,
+                last_poly_max_degree)
+                .unwrap();
 
             // Prepare the query positions. For simplicity, we draw some random integers 
             // instead of using Fiat-Shamir.
@@ -53,7 +53,9 @@ pub fn fold_and_batch_verifier(c: &mut Criterion) {
                 worker_last_poly_max_degree,
                 master_domain_size,
                 master_options.clone(),
-                NUM_QUERIES
+                NUM_QUERIES,
+                None,
+                0,
             );
 
             // Record the proof size to the file.
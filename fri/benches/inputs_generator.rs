@@ -78,27 +78,34 @@ fn generate_batched_fri_inputs(mode: Mode) {
                 inputs.push(build_evaluations(worker_domain_size, BLOWUP_FACTOR));
             }
 
+            // All worker nodes in this benchmark share the same domain size.
+            let worker_domain_sizes = vec![worker_domain_size; num_poly];
+
             // ------------------------ Step 1: worker commit phase --------------------------
             // Each worker node executes the FRI commit phase on their local input polynomial.
-            let (mut worker_nodes, worker_layer_commitments, batched_fri_inputs) = 
+            let (mut worker_nodes, worker_layer_commitments, batched_fri_inputs, _) =
             fold_and_batch_worker_commit::<QuadExtension<BaseElement>, Blake3, DefaultRandomCoin<Blake3>, MerkleTree<Blake3>>(
-                &inputs, 
-                num_poly, 
-                BLOWUP_FACTOR, 
-                FOLDING_FACTOR, 
-                worker_domain_size, 
-                worker_last_poly_max_degree, 
-                NUM_QUERIES
+                &inputs,
+                num_poly,
+                BLOWUP_FACTOR,
+                FOLDING_FACTOR,
+                &worker_domain_sizes,
+                worker_last_poly_max_degree,
+                NUM_QUERIES,
+                None,
             );
-            
+
 
             // -------------------------- Step 3: worker query phase --------------------------------
-            // Each worker node generates the FRI folding proof proving that the folding of its local 
+            // Each worker node generates the FRI folding proof proving that the folding of its local
             // polynomial was done correctly.
-            let (folding_proofs, worker_queried_evaluations) = 
+            let (folding_proofs, worker_queried_evaluations) =
             fold_and_batch_worker_query::<QuadExtension<BaseElement>, Blake3, MerkleTree<_>, DefaultRandomCoin<_>>(
-                &inputs, 
-                &mut worker_nodes, 
+                &inputs,
+                &mut worker_nodes,
+                &worker_domain_sizes,
+                worker_domain_size,
+                FOLDING_FACTOR,
                 &query_positions
             );
             
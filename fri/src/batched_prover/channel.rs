@@ -3,33 +3,37 @@ use alloc::vec::Vec;
 use crate::ProverChannel;
 use core::marker::PhantomData;
 
-use crypto::{ElementHasher, RandomCoin};
+use crypto::ElementHasher;
 use math::FieldElement;
 
+use crate::transcript::Transcript;
 
-pub struct BatchedFriProverChannel<E, H, R>
+
+pub struct BatchedFriProverChannel<E, H, T>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
-    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    T: Transcript<E, Hasher = H>,
 {
-    public_coin: R,
+    transcript: T,
     function_commitments: Vec<H::Digest>,
     layer_commitments: Vec<H::Digest>,
     _field_element: PhantomData<E>,
 }
 
 
-impl<E, H, R> BatchedFriProverChannel<E, H, R>
+impl<E, H, T> BatchedFriProverChannel<E, H, T>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
-    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    T: Transcript<E, Hasher = H>,
 {
 
-    pub fn new() -> Self {
+    /// Returns a new [BatchedFriProverChannel] that observes commitments and draws challenges
+    /// through `transcript`.
+    pub fn new(transcript: T) -> Self {
         BatchedFriProverChannel {
-            public_coin: RandomCoin::new(&[]),
+            transcript,
             function_commitments: Vec::new(),
             layer_commitments: Vec::new(),
             _field_element: PhantomData,
@@ -46,15 +50,27 @@ where
 
     pub fn push_function_commitment(&mut self, function_root: H::Digest) {
         self.function_commitments.push(function_root);
-        self.public_coin.reseed(function_root);
+        self.transcript.observe_digest(function_root);
+    }
+
+    /// Absorbs `digest` into the transcript without recording it anywhere in the proof.
+    ///
+    /// Used by [fold_and_batch_master_commit](crate::BatchedFriProver::fold_and_batch_master_commit)
+    /// to bind the master's batching challenge to every worker's own layer commitments: those
+    /// commitments are already carried in the proof by
+    /// [FoldAndBatchProof](crate::fold_and_batch_proof::FoldAndBatchProof) itself, so recording
+    /// them again in this channel's own `function_commitments`/`layer_commitments` (which are
+    /// serialized as this prover's own data) would be redundant.
+    pub fn reseed(&mut self, digest: H::Digest) {
+        self.transcript.observe_digest(digest);
     }
 
     pub fn draw_batched_fri_challange(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw batched FRI challenge")
+        self.transcript.challenge_field_element()
     }
 
-    pub fn draw_query_positions(&mut self, domain_size: usize, num_queries: usize, nonce: u64) -> Vec<usize> {
-        
+    pub fn draw_query_positions(&mut self, domain_size: usize, num_queries: usize) -> Vec<usize> {
+
         assert!(domain_size >= 8, "domain size must be at least 8, but was {domain_size}");
         assert!(
             domain_size.is_power_of_two(),
@@ -62,27 +78,30 @@ where
         );
         assert!(num_queries > 0, "number of queries must be greater than zero");
 
-        self.public_coin
-            .draw_integers(num_queries, domain_size, nonce)
-            .expect("failed to draw query positions")
+        self.transcript.challenge_integers(num_queries, domain_size)
+    }
+
+    /// Finds a proof-of-work nonce satisfying `grinding_factor` and absorbs it into the
+    /// transcript. See [Transcript::grind_query_seed].
+    pub fn grind_query_seed(&mut self, grinding_factor: u32) -> u64 {
+        self.transcript.grind_query_seed(grinding_factor)
     }
 }
 
-impl<E, H, R> ProverChannel<E> for BatchedFriProverChannel<E, H, R>
+impl<E, H, T> ProverChannel<E> for BatchedFriProverChannel<E, H, T>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
-    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    T: Transcript<E, Hasher = H>,
 {
     type Hasher = H;
 
     fn commit_fri_layer(&mut self, layer_root: H::Digest) {
         self.layer_commitments.push(layer_root);
-        self.public_coin.reseed(layer_root);
+        self.transcript.observe_digest(layer_root);
     }
 
     fn draw_fri_alpha(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw FRI alpha")
+        self.transcript.challenge_field_element()
     }
 }
-
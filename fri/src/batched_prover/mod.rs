@@ -2,9 +2,10 @@ use alloc::vec::Vec;
 use math::StarkField;
 use utils::{
     flatten_vector_elements, group_slice_elements, transpose_slice};
-use crypto::{Hasher, RandomCoin};
+use crypto::Hasher;
 use crypto::{ElementHasher, VectorCommitment};
-use math::FieldElement;
+use math::{fft, FieldElement};
+use rand_utils::rand_vector;
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 
@@ -12,39 +13,50 @@ pub(crate) mod channel;
 use channel::BatchedFriProverChannel;
 
 use crate::fold_and_batch_proof::FoldingProof;
-use crate::folding::fold_positions;
-use crate::{build_layer_commitment, BatchedFriProof, FoldAndBatchProof, FriLayer, FriOptions, FriProofLayer, FriProver};
+use crate::fold_and_batch_prover::fold_query_positions;
+use crate::folding::{apply_drp, fold_positions};
+use crate::transcript::Transcript;
+use crate::{build_layer_commitment, BatchedFriProof, FoldAndBatchProof, FriLayer, FriOptions, FriProofLayer, FriProver, ProverChannel};
 
 #[cfg(test)]
 mod tests;
 
-pub struct BatchedFriProver<E, H, V, R>
+pub struct BatchedFriProver<E, H, V, T>
 where
     E: FieldElement + StarkField,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
-    R: RandomCoin<BaseField = E::BaseField, Hasher = H>
+    T: Transcript<E, Hasher = H>,
 {
-    fri_prover: FriProver<E, BatchedFriProverChannel<E, H, R>, H, V>,
+    fri_prover: FriProver<E, BatchedFriProverChannel<E, H, T>, H, V>,
     function_layers: Vec<FriLayer<E, H, V>>,
-    channel: BatchedFriProverChannel<E, H, R>,
+    channel: BatchedFriProverChannel<E, H, T>,
+    options: FriOptions,
+    /// The proof-of-work nonce found by [fold_and_batch_master_commit](Self::fold_and_batch_master_commit),
+    /// carried over to [fold_and_batch_master_query](Self::fold_and_batch_master_query) since the
+    /// two halves of a Fold-and-Batch proof are now built across two separate calls instead of
+    /// one [build_proof](Self::build_proof) call.
+    pow_nonce: u64,
 }
 
-impl<E, H, V, R> BatchedFriProver<E, H, V, R>
+impl<E, H, V, T> BatchedFriProver<E, H, V, T>
 where
     E: FieldElement + StarkField,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
-    R: RandomCoin<BaseField = E::BaseField, Hasher = H>
+    T: Transcript<E, Hasher = H>,
 {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new Batched FRI prover instantiated with the provided `options`.
-    pub fn new(options: FriOptions) -> Self {
+    /// Returns a new Batched FRI prover that observes commitments and draws challenges through
+    /// `transcript`, instantiated with the provided `options`.
+    pub fn new(options: FriOptions, transcript: T) -> Self {
         BatchedFriProver {
-            fri_prover: FriProver::new(options),
+            fri_prover: FriProver::new(options.clone()),
             function_layers: Vec::new(),
-            channel: BatchedFriProverChannel::new(),
+            channel: BatchedFriProverChannel::new(transcript),
+            options,
+            pow_nonce: 0,
         }
     }
 
@@ -66,14 +78,23 @@ where
     pub fn num_layers(&self) -> usize {
         self.fri_prover.num_layers()
     }
-    
+
+    /// Returns the proof-of-work grinding factor (in bits) configured for this prover.
+    ///
+    /// Every grinding bit lets the verifier accept one fewer query for the same target
+    /// soundness, at the cost of the prover searching for a nonce before drawing query
+    /// positions. A value of 0 (the default) disables grinding entirely.
+    pub fn grinding_factor(&self) -> u32 {
+        self.options.grinding_factor()
+    }
+
 
     /// Takes the evaluation vector of a single polynomial and builds the FriLayer for that polynomial.
     /// This method performs two operations:
     /// 1. Compute the commitment to the evaluation vector `evaluations` and push it into the prover channel.
     /// 2. Constructs a FriLayer storing the evaluations and the commitment, then store that FriLayer
     /// in the prover's `function_layers` field.
-    fn build_function_layer<const N: usize>(channel: &mut BatchedFriProverChannel<E, H, R>, evaluations: &[E]) -> FriLayer<E, H, V> {
+    fn build_function_layer<const N: usize>(channel: &mut BatchedFriProverChannel<E, H, T>, evaluations: &[E]) -> FriLayer<E, H, V> {
         
         // Commit to the function evaluations. We do this by first transposing the
         // evaluations into a matrix of N columns, then hashing each row into a digest, and finally, 
@@ -89,7 +110,85 @@ where
     }
 
 
-    /// For each function layer, create its corresponding proof layer consisting of the evaluations 
+    /// Commits to all of `inputs` at once using a single vector commitment, rather than one
+    /// commitment per polynomial as [build_function_layer](Self::build_function_layer) does.
+    ///
+    /// Leaf `j` of the resulting commitment is the hash of the concatenation of every input
+    /// polynomial's `j`-th coset of `N` evaluations, so a single opening proof at a query
+    /// position authenticates all of `inputs` at that position at once. This cuts a batch of
+    /// `num_poly` commitments and opening proofs down to one of each, at the cost of requiring
+    /// every input to share the same evaluation-vector length (i.e. the same domain size); a
+    /// true `BatchMerkleTree` supporting inputs of differing heights, as in plonky2's
+    /// batch-FRI oracle, is a natural follow-up once `V` supports committing leaves of unequal
+    /// subtree depth.
+    ///
+    /// # Panics
+    /// Panics if `inputs` is empty, or if the input evaluation vectors are not all the same
+    /// length.
+    ///
+    /// With the `concurrent` feature enabled, the leaf at each row is hashed on `rayon`'s thread
+    /// pool instead of sequentially, since every row's hash is independent of every other; the
+    /// leaves are collected back in row order, so the resulting commitment is bit-identical to
+    /// the sequential path.
+    fn build_combined_function_layer<const N: usize>(
+        channel: &mut BatchedFriProverChannel<E, H, T>,
+        inputs: &Vec<Vec<E>>,
+    ) -> (V, Vec<Vec<[E; N]>>) {
+        assert!(!inputs.is_empty(), "at least one input polynomial is required");
+
+        let transposed: Vec<Vec<[E; N]>> = inputs.iter().map(|input| transpose_slice(input)).collect();
+        let num_leaves = transposed[0].len();
+        assert!(
+            transposed.iter().all(|rows| rows.len() == num_leaves),
+            "all input polynomials must share the same domain size to be committed into a single combined function layer"
+        );
+
+        let hash_row = |row: usize| {
+            let mut combined_row = Vec::with_capacity(inputs.len() * N);
+            for poly_rows in transposed.iter() {
+                combined_row.extend_from_slice(&poly_rows[row]);
+            }
+            H::hash_elements(&combined_row)
+        };
+
+        #[cfg(feature = "concurrent")]
+        let leaves: Vec<H::Digest> = (0..num_leaves).into_par_iter().map(hash_row).collect();
+        #[cfg(not(feature = "concurrent"))]
+        let leaves: Vec<H::Digest> = (0..num_leaves).map(hash_row).collect();
+
+        let commitment =
+            V::new(leaves).expect("failed to construct combined batched FRI function layer commitment");
+        channel.push_function_commitment(commitment.commitment());
+
+        (commitment, transposed)
+    }
+
+    /// Builds a single opening proof for every input committed by
+    /// [build_combined_function_layer](Self::build_combined_function_layer), covering every
+    /// polynomial's evaluations at each of `positions` at once.
+    fn compute_combined_batching_proof<const N: usize>(
+        commitment: &V,
+        transposed: &[Vec<[E; N]>],
+        positions: &[usize],
+        domain_size: usize,
+    ) -> (Vec<Vec<[E; N]>>, V::MultiProof) {
+        let folded_positions = fold_positions(positions, domain_size, N);
+
+        let (_, opening_proof) = commitment
+            .open_many(&folded_positions)
+            .expect("failed to generate a combined batch opening proof for FRI function layer queries");
+
+        let mut queried_values = Vec::with_capacity(folded_positions.len());
+        for &folded_position in folded_positions.iter() {
+            let row: Vec<[E; N]> = transposed.iter().map(|rows| rows[folded_position]).collect();
+            queried_values.push(row);
+        }
+
+        (queried_values, opening_proof)
+    }
+
+
+    /// For each function layer, create its corresponding proof layer consisting of the evaluations
     /// of that function at the queried positions(`positions`) and the opening proofs of those evaluations 
     /// against the vector commitment of that function.
     ///
@@ -159,9 +258,11 @@ where
 
 
         // -------------------------------- Step 4 ---------------------------------------------
-        // Sample the query positions using Fiat-Shamir.
-        // TODO: consider using grinding?
-        let mut query_positions = self.channel.draw_query_positions(domain_size, num_queries, 0);
+        // Search for a proof-of-work nonce binding the query positions to the transcript as it
+        // stands after all commitments and the batching challenge have been absorbed, then
+        // sample the query positions using Fiat-Shamir.
+        let pow_nonce = self.channel.grind_query_seed(self.grinding_factor());
+        let mut query_positions = self.channel.draw_query_positions(domain_size, num_queries);
 
         // Remove any potential duplicates from the positions as the prover will send openings only
         // for unique queries.
@@ -176,37 +277,573 @@ where
         let layer_commitments = self.channel.layer_commitments().to_vec();
         let function_commitments = self.channel.function_commitments().to_vec();
         let evaluations = query_positions.iter().map(|&p| batched_evaluations[p]).collect::<Vec<_>>();
-        
-        BatchedFriProof::new::<E>(fri_proof, evaluations, batching_proofs, layer_commitments, function_commitments)
+
+        BatchedFriProof::new::<E>(fri_proof, evaluations, batching_proofs, layer_commitments, function_commitments, pow_nonce)
+    }
+
+
+    /// Variant of [build_proof](Self::build_proof) with zero-knowledge hiding: before it is
+    /// committed as its own function layer, every input in `inputs` is blinded by adding a
+    /// uniformly random polynomial of degree at most `blinding_degree`, independent per input, so
+    /// that the per-function Merkle proof [verify_opening_proofs](crate::batched_verifier::BatchedFriVerifier)
+    /// opens at each query position reveals only the blinded value rather than the real input's
+    /// own evaluation there -- adapting the blinding-factor technique
+    /// [FoldingOptions::zk](crate::fold_and_batch_prover::FoldingOptions::zk) already uses for
+    /// Fold-and-Batch's worker last layers. An earlier version of this method instead appended a
+    /// single random mask as one extra input and relied on the random linear combination alone to
+    /// hide the rest, but every input (mask included) is still opened individually and in the
+    /// clear by the very same Merkle proofs, so that approach hid nothing.
+    ///
+    /// Blinding every input independently, before any commitment is made, means the verifier needs
+    /// no dedicated support for this beyond the usual [verify](crate::batched_verifier::BatchedFriVerifier::verify)
+    /// call: the random-linear-combination check between the Merkle-opened function evaluations
+    /// and the claimed batched evaluations passes exactly as it would unblinded, since both sides
+    /// were blinded the same way. Note: since the `FriOptions` this prover is configured with
+    /// comes from outside this crate, zero-knowledge hiding is opted into by calling this method
+    /// instead of through a `FriOptions::with_zero_knowledge()` flag.
+    ///
+    /// Unlike [build_proof](Self::build_proof), `inputs` is taken by value rather than by
+    /// reference, so each input can be blinded in place without an extra clone of every (likely
+    /// large) input evaluation vector.
+    pub fn build_proof_zk(&mut self, mut inputs: Vec<Vec<E>>, domain_size: usize, num_queries: usize, blinding_degree: usize) -> BatchedFriProof<H> {
+        for input in inputs.iter_mut() {
+            let blinding = random_blinding_evaluations(blinding_degree, input.len());
+            for (value, blind) in input.iter_mut().zip(blinding.iter()) {
+                *value += *blind;
+            }
+        }
+
+        self.build_proof(&inputs, domain_size, num_queries)
+    }
+
+
+    /// Variant of [build_proof](Self::build_proof) for batching polynomials of differing degree
+    /// bounds, so that callers no longer need to pad every input to a common domain size before
+    /// batching.
+    ///
+    /// Unlike [build_proof](Self::build_proof), which combines all `inputs` into a single
+    /// evaluation vector up front via [combine_poly_evaluations], this method sorts the inputs
+    /// by decreasing evaluation-vector length and folds the running batched codeword down one
+    /// FRI layer at a time, injecting each polynomial's contribution — scaled by the appropriate
+    /// power of the batching challenge — as soon as the codeword has been folded down to that
+    /// polynomial's own domain size. `domain_size` must equal the length of the largest input.
+    pub fn build_proof_heterogeneous(&mut self, inputs: &Vec<Vec<E>>, domain_size: usize, num_queries: usize) -> BatchedFriProof<H> {
+
+        // -------------------------------- Step 1 ---------------------------------------------
+        // Build the function layers. Each function layer corresponds to one input polynomial.
+        for i in 0..inputs.len() {
+            let function_layer = match self.folding_factor() {
+                2 => Self::build_function_layer::<2>(&mut self.channel, &inputs[i]),
+                4 => Self::build_function_layer::<4>(&mut self.channel, &inputs[i]),
+                8 => Self::build_function_layer::<8>(&mut self.channel, &inputs[i]),
+                16 => Self::build_function_layer::<16>(&mut self.channel, &inputs[i]),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+
+            self.function_layers.push(function_layer);
+        }
+
+        // -------------------------------- Step 2 ---------------------------------------------
+        // Draw the batching challenge, then progressively fold the largest input's evaluations
+        // down, injecting every other input's contribution the moment the running codeword
+        // reaches that input's own domain size.
+        let challenge = self.channel.draw_batched_fri_challange();
+        let batched_evaluations = combine_poly_evaluations_heterogeneous(inputs, challenge, |evaluations| {
+            match self.folding_factor() {
+                2 => self.fold_and_commit::<2>(evaluations),
+                4 => self.fold_and_commit::<4>(evaluations),
+                8 => self.fold_and_commit::<8>(evaluations),
+                16 => self.fold_and_commit::<16>(evaluations),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            }
+        });
+
+        // -------------------------------- Step 3 ---------------------------------------------
+        // Perform the remainder of the FRI folding phase on whatever is left of the batched
+        // codeword once every input has been folded in.
+        self.fri_prover.build_layers(&mut self.channel, batched_evaluations.clone());
+
+        // -------------------------------- Step 4 ---------------------------------------------
+        let pow_nonce = self.channel.grind_query_seed(self.grinding_factor());
+        let mut query_positions = self.channel.draw_query_positions(domain_size, num_queries);
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        // -------------------------------- Step 5 ---------------------------------------------
+        let fri_proof = self.fri_prover.build_proof(&query_positions);
+        let batching_proofs = self.compute_batching_proofs(&query_positions, domain_size);
+        let layer_commitments = self.channel.layer_commitments().to_vec();
+        let function_commitments = self.channel.function_commitments().to_vec();
+        let evaluations = query_positions.iter().map(|&p| batched_evaluations[p]).collect::<Vec<_>>();
+
+        BatchedFriProof::new::<E>(fri_proof, evaluations, batching_proofs, layer_commitments, function_commitments, pow_nonce)
+    }
+
+    /// Variant of [build_proof](Self::build_proof) for batching polynomials that share a single
+    /// domain size but have differing degree bounds, given by `degree_bounds` (one entry per
+    /// vector in `inputs`, same order). Every input is combined via
+    /// [combine_poly_evaluations_with_degree_bounds], which degree-corrects each polynomial up to
+    /// `degree_bounds.iter().max()` before folding it into the random linear combination, rather
+    /// than via the plain [combine_poly_evaluations] this method's sibling uses.
+    ///
+    /// # Panics
+    /// Panics if `degree_bounds.len() != inputs.len()`, or if some entry of `degree_bounds`
+    /// exceeds the largest one.
+    pub fn build_proof_with_degree_bounds(
+        &mut self,
+        inputs: &Vec<Vec<E>>,
+        degree_bounds: &[usize],
+        domain_size: usize,
+        num_queries: usize,
+    ) -> BatchedFriProof<H> {
+        for i in 0..inputs.len() {
+            let function_layer = match self.folding_factor() {
+                2 => Self::build_function_layer::<2>(&mut self.channel, &inputs[i]),
+                4 => Self::build_function_layer::<4>(&mut self.channel, &inputs[i]),
+                8 => Self::build_function_layer::<8>(&mut self.channel, &inputs[i]),
+                16 => Self::build_function_layer::<16>(&mut self.channel, &inputs[i]),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+
+            self.function_layers.push(function_layer);
+        }
+
+        let challenge = self.channel.draw_batched_fri_challange();
+        let max_degree_bound = *degree_bounds.iter().max().expect("degree_bounds must not be empty");
+        let batched_evaluations = combine_poly_evaluations_with_degree_bounds(
+            inputs,
+            degree_bounds,
+            max_degree_bound,
+            self.domain_offset(),
+            challenge,
+        );
+
+        self.fri_prover.build_layers(&mut self.channel, batched_evaluations.clone());
+
+        let pow_nonce = self.channel.grind_query_seed(self.grinding_factor());
+        let mut query_positions = self.channel.draw_query_positions(domain_size, num_queries);
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        let fri_proof = self.fri_prover.build_proof(&query_positions);
+        let batching_proofs = self.compute_batching_proofs(&query_positions, domain_size);
+        let layer_commitments = self.channel.layer_commitments().to_vec();
+        let function_commitments = self.channel.function_commitments().to_vec();
+        let evaluations = query_positions.iter().map(|&p| batched_evaluations[p]).collect::<Vec<_>>();
+
+        BatchedFriProof::new::<E>(fri_proof, evaluations, batching_proofs, layer_commitments, function_commitments, pow_nonce)
+    }
+
+    /// Folds `evaluations` by this prover's folding factor in place, committing the
+    /// pre-folding layer into the channel exactly as [FriProver::build_layers] would. Used by
+    /// [build_proof_heterogeneous](Self::build_proof_heterogeneous) to advance the batched
+    /// codeword between degree-bound injection points.
+    fn fold_and_commit<const N: usize>(&mut self, evaluations: &mut Vec<E>) {
+        let transposed_evaluations = transpose_slice(evaluations);
+        let commitment = build_layer_commitment::<_, _, V, N>(&transposed_evaluations)
+            .expect("failed to construct batched FRI intermediate layer commitment");
+        self.channel.commit_fri_layer(commitment.commitment());
+
+        let alpha = self.channel.draw_fri_alpha();
+        *evaluations = apply_drp(&transposed_evaluations, self.domain_offset(), alpha);
+    }
+
+    // AGGREGATED PROOF COMMIT/BUILD PHASES
+    // --------------------------------------------------------------------------------------------
+    /// First phase of building a proof intended to be verified together with others via
+    /// [BatchedFriVerifier::verify_aggregated](crate::BatchedFriVerifier::verify_aggregated):
+    /// commits to each of `inputs`' function layers and returns their commitments, so a caller
+    /// building several such proofs together can collect every proof's function commitments
+    /// before deriving the externally-shared challenge and query positions that every proof in
+    /// the batch must use (see [build_proof_for_aggregation](Self::build_proof_for_aggregation)).
+    pub fn commit_aggregated_function_layers(&mut self, inputs: &Vec<Vec<E>>) -> Vec<H::Digest> {
+        for i in 0..inputs.len() {
+            let function_layer = match self.folding_factor() {
+                2 => Self::build_function_layer::<2>(&mut self.channel, &inputs[i]),
+                4 => Self::build_function_layer::<4>(&mut self.channel, &inputs[i]),
+                8 => Self::build_function_layer::<8>(&mut self.channel, &inputs[i]),
+                16 => Self::build_function_layer::<16>(&mut self.channel, &inputs[i]),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+
+            self.function_layers.push(function_layer);
+        }
+
+        self.channel.function_commitments().to_vec()
+    }
+
+    /// Second phase of building a proof intended for aggregation, once every proof's function
+    /// commitments (from [commit_aggregated_function_layers](Self::commit_aggregated_function_layers))
+    /// have been absorbed into a shared transcript and used to derive `challenge` and
+    /// `query_positions`, exactly as
+    /// [BatchedFriVerifier::verify_aggregated](crate::BatchedFriVerifier::verify_aggregated) will
+    /// independently reconstruct them.
+    ///
+    /// `exponent_offset` is the number of polynomials already claimed by earlier proofs in the
+    /// aggregate batch, so that this proof's own polynomials continue the same sequence of
+    /// challenge powers rather than restarting at `challenge^0`, letting every polynomial across
+    /// every proof in the batch be treated as one combined batch by the verifier.
+    ///
+    /// Proof-of-work grinding is not supported for proofs built this way: the proof's nonce is
+    /// always `0`, since a meaningful grinding check would need to bind to the same shared
+    /// transcript every other proof in the batch is checked against, which would require
+    /// coordinating the nonce search across every prover building a proof for the same batch.
+    pub fn build_proof_for_aggregation(
+        &mut self,
+        inputs: &Vec<Vec<E>>,
+        domain_size: usize,
+        challenge: E,
+        exponent_offset: usize,
+        query_positions: &[usize],
+    ) -> BatchedFriProof<H> {
+        let batched_evaluations = combine_poly_evaluations_from_offset(inputs, challenge, exponent_offset);
+        self.fri_prover.build_layers(&mut self.channel, batched_evaluations.clone());
+
+        let fri_proof = self.fri_prover.build_proof(query_positions);
+        let batching_proofs = self.compute_batching_proofs(query_positions, domain_size);
+        let layer_commitments = self.channel.layer_commitments().to_vec();
+        let function_commitments = self.channel.function_commitments().to_vec();
+        let evaluations = query_positions.iter().map(|&p| batched_evaluations[p]).collect::<Vec<_>>();
+
+        BatchedFriProof::new::<E>(fri_proof, evaluations, batching_proofs, layer_commitments, function_commitments, 0)
+    }
+
+    // FOLD-AND-BATCH MASTER COMMIT/QUERY PHASES
+    // --------------------------------------------------------------------------------------------
+    /// Runs the master's half of the Fold-and-Batch commit phase: batches every worker's last
+    /// layer, handed off in `batched_fri_inputs`, into this prover's own batched FRI codeword,
+    /// then samples query positions for the whole protocol.
+    ///
+    /// `worker_layer_commitments` holds each worker's own (already-committed) intermediate layer
+    /// commitments; these are reseeded into the channel, binding the batching challenge drawn
+    /// below to every worker's folding, but are not recorded again in this prover's own
+    /// `function_commitments`/`layer_commitments`, since [FoldAndBatchProof](crate::fold_and_batch_proof::FoldAndBatchProof)
+    /// already carries them directly.
+    ///
+    /// `batched_fri_inputs` may hold vectors of differing lengths, one per worker, since workers
+    /// may end their own local folding at differing degrees (see
+    /// [fold_and_batch_worker_commit](crate::fold_and_batch_prover::fold_and_batch_worker_commit));
+    /// a function layer is committed individually for each worker, and they are combined the way
+    /// [build_proof_heterogeneous](Self::build_proof_heterogeneous) combines a flat set of inputs
+    /// of differing degree bounds: sorted by decreasing length, with the running codeword folded
+    /// down one layer at a time until it reaches the next worker's own domain size, at which point
+    /// that worker's (scaled) contribution is injected.
+    ///
+    /// Query positions are sampled over `sampling_domain_size`, the largest *starting* worker
+    /// domain rather than this prover's own (already-folded-down) domain, so the worker nodes can
+    /// fold the very same positions down to query their own local layers.
+    ///
+    /// Returns the fully-folded batched codeword (so [fold_and_batch_master_query](Self::fold_and_batch_master_query)
+    /// can read off the evaluations it commits to), the sampled query positions, and the batching
+    /// challenge drawn along the way.
+    pub fn fold_and_batch_master_commit(
+        &mut self,
+        sampling_domain_size: usize,
+        num_queries: usize,
+        worker_layer_commitments: &Vec<Vec<H::Digest>>,
+        batched_fri_inputs: Vec<Vec<E>>,
+    ) -> (Vec<E>, Vec<usize>, E) {
+        for commitments in worker_layer_commitments.iter() {
+            for &commitment in commitments.iter() {
+                self.channel.reseed(commitment);
+            }
+        }
+
+        for input in batched_fri_inputs.iter() {
+            let function_layer = match self.folding_factor() {
+                2 => Self::build_function_layer::<2>(&mut self.channel, input),
+                4 => Self::build_function_layer::<4>(&mut self.channel, input),
+                8 => Self::build_function_layer::<8>(&mut self.channel, input),
+                16 => Self::build_function_layer::<16>(&mut self.channel, input),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+            self.function_layers.push(function_layer);
+        }
+
+        let batched_fri_challenge = self.channel.draw_batched_fri_challange();
+        let batched_evaluations = combine_poly_evaluations_heterogeneous(&batched_fri_inputs, batched_fri_challenge, |evaluations| {
+            match self.folding_factor() {
+                2 => self.fold_and_commit::<2>(evaluations),
+                4 => self.fold_and_commit::<4>(evaluations),
+                8 => self.fold_and_commit::<8>(evaluations),
+                16 => self.fold_and_commit::<16>(evaluations),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            }
+        });
+
+        self.fri_prover.build_layers(&mut self.channel, batched_evaluations.clone());
+
+        self.pow_nonce = self.channel.grind_query_seed(self.grinding_factor());
+        let mut query_positions = self.channel.draw_query_positions(sampling_domain_size, num_queries);
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        (batched_evaluations, query_positions, batched_fri_challenge)
+    }
+
+    /// Runs the master's half of the Fold-and-Batch query phase, assembling the
+    /// [FoldAndBatchProof](crate::fold_and_batch_proof::FoldAndBatchProof) from the pieces
+    /// produced by [fold_and_batch_master_commit](Self::fold_and_batch_master_commit) and by
+    /// every worker's own commit and query phases.
+    ///
+    /// `query_positions` are the raw positions [fold_and_batch_master_commit](Self::fold_and_batch_master_commit)
+    /// sampled over `sampling_domain_size`; they are folded down to `master_domain_size` to query
+    /// this prover's own batched FRI layers, and down to each worker's own function domain size
+    /// (independently, since workers may end their folding at differing degrees) to query that
+    /// worker's function layer.
+    pub fn fold_and_batch_master_query(
+        &mut self,
+        sampling_domain_size: usize,
+        master_domain_size: usize,
+        worker_layer_commitments: Vec<Vec<H::Digest>>,
+        query_positions: Vec<usize>,
+        folding_proofs: Vec<FoldingProof>,
+        worker_evaluations: Vec<Vec<E>>,
+        batched_evaluations: Vec<E>,
+        blinding_evaluations: Vec<E>,
+    ) -> FoldAndBatchProof<H> {
+        let mut master_query_positions =
+            fold_query_positions(&query_positions, sampling_domain_size, master_domain_size, self.folding_factor());
+        master_query_positions.sort_unstable();
+        master_query_positions.dedup();
+
+        let fri_proof = self.fri_prover.build_proof(&master_query_positions);
+        let function_openings = self.compute_heterogeneous_batching_proofs(&query_positions, sampling_domain_size);
+        let master_layer_commitments = self.channel.layer_commitments().to_vec();
+        let function_commitments = self.channel.function_commitments().to_vec();
+        let master_evaluations = master_query_positions.iter().map(|&p| batched_evaluations[p]).collect::<Vec<_>>();
+
+        FoldAndBatchProof::new::<E>(
+            folding_proofs,
+            fri_proof,
+            worker_evaluations,
+            master_evaluations,
+            worker_layer_commitments,
+            master_layer_commitments,
+            function_commitments,
+            function_openings,
+            blinding_evaluations,
+            self.pow_nonce,
+        )
+    }
+
+    /// Like [compute_batching_proofs](Self::compute_batching_proofs), but for function layers
+    /// that may each live on a different domain size, as committed by
+    /// [fold_and_batch_master_commit](Self::fold_and_batch_master_commit): `positions`, sampled
+    /// over `sampling_domain_size`, are folded down to each function layer's own domain size
+    /// before it is queried.
+    fn compute_heterogeneous_batching_proofs(&mut self, positions: &[usize], sampling_domain_size: usize) -> Vec<FriProofLayer> {
+        assert!(!self.function_layers.is_empty(), "Batched FRI function layers have not been built yet");
+
+        let folding_factor = self.folding_factor();
+        self.function_layers
+            .iter()
+            .map(|layer| {
+                let domain_size = layer.evaluations().len();
+                let layer_positions = fold_query_positions(positions, sampling_domain_size, domain_size, folding_factor);
+
+                match folding_factor {
+                    2 => query_layer::<E, H, V, 2>(layer, &layer_positions, domain_size),
+                    4 => query_layer::<E, H, V, 4>(layer, &layer_positions, domain_size),
+                    8 => query_layer::<E, H, V, 8>(layer, &layer_positions, domain_size),
+                    16 => query_layer::<E, H, V, 16>(layer, &layer_positions, domain_size),
+                    _ => unimplemented!("folding factor {} is not supported", folding_factor),
+                }
+            })
+            .collect()
     }
 }
 
 
 
-/// Takes a vector of evaluation vectors, return their linear combination using the 
-/// batched FRI challenge. If `evaluations` contains vectors `v_0, ..., v_l`, and the 
+/// Returns the evaluations, over a domain of size `domain_size`, of a random polynomial of degree
+/// at most `degree`, for use as a zero-knowledge blinding polynomial -- by
+/// [build_proof_zk](BatchedFriProver::build_proof_zk) here, and by
+/// [fold_and_batch_worker_commit](crate::fold_and_batch_prover::fold_and_batch_worker_commit) for
+/// Fold-and-Batch's worker last layers.
+pub(crate) fn random_blinding_evaluations<E: FieldElement + StarkField>(degree: usize, domain_size: usize) -> Vec<E> {
+    let mut coefficients = rand_vector::<E>(degree + 1);
+    coefficients.resize(domain_size, E::ZERO);
+
+    let twiddles = fft::get_twiddles::<E::BaseField>(domain_size);
+    fft::evaluate_poly(&mut coefficients, &twiddles);
+    coefficients
+}
+
+
+/// Takes a vector of evaluation vectors, return their linear combination using the
+/// batched FRI challenge. If `evaluations` contains vectors `v_0, ..., v_l`, and the
 /// `batched_fri_challenge` is `a`, then the returned vector is
 /// `v_0 + a * v_1 + a^2 * v_2 + ... + a^l * v_l`.
+///
+/// Every output position is combined independently of every other, so with the `concurrent`
+/// feature enabled this runs over `rayon`'s thread pool via [utils::iterators]; without it, the
+/// same code runs single-threaded and produces bit-identical output.
 pub fn combine_poly_evaluations<E: FieldElement>(evaluations: &Vec<Vec<E>>, batched_fri_challenge: E) -> Vec<E> {
-    
+
     assert!(evaluations.len() > 0, "Number of evaluation vectors must be at least 1");
 
     let eval_vec_size = evaluations[0].len();
     let num_poly = evaluations.len();
-    let mut combined_evaluations = Vec::with_capacity(eval_vec_size);
-    for j in 0..eval_vec_size {
+    let powers: Vec<E> = core::iter::successors(Some(E::ONE), |&p| Some(p * batched_fri_challenge))
+        .take(num_poly)
+        .collect();
+    let combine_position = |j: usize| {
         let mut combined_entry = E::ZERO;
-        let mut multiplier = E::ONE;
         for i in 0..num_poly {
-            combined_entry += multiplier * evaluations[i][j];
-            multiplier *= batched_fri_challenge;
+            combined_entry += powers[i] * evaluations[i][j];
+        }
+        combined_entry
+    };
+
+    #[cfg(feature = "concurrent")]
+    let combined_evaluations = (0..eval_vec_size).into_par_iter().map(combine_position).collect();
+    #[cfg(not(feature = "concurrent"))]
+    let combined_evaluations = (0..eval_vec_size).map(combine_position).collect();
+
+    combined_evaluations
+}
+
+
+/// Like [combine_poly_evaluations], but starting the sequence of challenge powers at
+/// `batched_fri_challenge^exponent_offset` instead of `batched_fri_challenge^0`. Used by
+/// [BatchedFriProver::build_proof_for_aggregation] so that several independent calls can each
+/// claim a distinct, non-overlapping slice of one globally continuous sequence of powers, letting
+/// a verifier treat every polynomial across every call as one combined batch.
+fn combine_poly_evaluations_from_offset<E: FieldElement>(
+    evaluations: &Vec<Vec<E>>,
+    batched_fri_challenge: E,
+    exponent_offset: usize,
+) -> Vec<E> {
+    assert!(evaluations.len() > 0, "Number of evaluation vectors must be at least 1");
+
+    let eval_vec_size = evaluations[0].len();
+    let num_poly = evaluations.len();
+    let base = batched_fri_challenge.exp((exponent_offset as u64).into());
+    let powers: Vec<E> = core::iter::successors(Some(base), |&p| Some(p * batched_fri_challenge))
+        .take(num_poly)
+        .collect();
+    let combine_position = |j: usize| {
+        let mut combined_entry = E::ZERO;
+        for i in 0..num_poly {
+            combined_entry += powers[i] * evaluations[i][j];
+        }
+        combined_entry
+    };
+
+    #[cfg(feature = "concurrent")]
+    let combined_evaluations = (0..eval_vec_size).into_par_iter().map(combine_position).collect();
+    #[cfg(not(feature = "concurrent"))]
+    let combined_evaluations = (0..eval_vec_size).map(combine_position).collect();
+
+    combined_evaluations
+}
+
+
+/// Like [combine_poly_evaluations], but for input polynomials of differing evaluation-vector
+/// lengths (i.e. differing degree bounds). `inputs` is first sorted by decreasing length; the
+/// longest polynomial seeds the running codeword (scaled by `batched_fri_challenge^0`), and
+/// `fold_layer` is invoked to fold the running codeword by the FRI folding factor and commit the
+/// pre-folding layer into the channel whenever the codeword still needs to shrink to reach the
+/// domain size of the next polynomial to inject. Once a polynomial's domain size is reached, its
+/// evaluations are added in, scaled by the matching power of `batched_fri_challenge`.
+///
+/// # Panics
+/// Panics if `inputs` is empty, or if some polynomial's evaluation-vector length does not divide
+/// evenly down to from the largest one by repeated application of the folding factor used by
+/// `fold_layer`.
+fn combine_poly_evaluations_heterogeneous<E: FieldElement>(
+    inputs: &Vec<Vec<E>>,
+    batched_fri_challenge: E,
+    mut fold_layer: impl FnMut(&mut Vec<E>),
+) -> Vec<E> {
+    assert!(!inputs.is_empty(), "Number of evaluation vectors must be at least 1");
+
+    let order = sort_by_degree_bound(inputs);
+    let mut batched_evaluations = inputs[order[0]].clone();
+    let mut power = E::ONE;
+
+    for &poly_index in order[1..].iter() {
+        let target_len = inputs[poly_index].len();
+
+        while batched_evaluations.len() > target_len {
+            fold_layer(&mut batched_evaluations);
+        }
+        assert_eq!(
+            batched_evaluations.len(), target_len,
+            "polynomial with evaluation vector of length {} does not lie on a FRI folding layer boundary", target_len
+        );
+
+        power *= batched_fri_challenge;
+        for (dst, &src) in batched_evaluations.iter_mut().zip(inputs[poly_index].iter()) {
+            *dst += power * src;
         }
-        combined_evaluations.push(combined_entry);
     }
 
+    batched_evaluations
+}
+
+/// Like [combine_poly_evaluations], but for polynomials that share a single domain size while
+/// having differing degree bounds, given by `degree_bounds` (one entry per vector in
+/// `evaluations`, same order). Before being added into the random linear combination, the
+/// evaluation of a polynomial with degree bound `d` at domain point `x` is multiplied by
+/// `x^(max_degree_bound - d)`, the standard degree-correction trick that makes every term of the
+/// combination behave like a polynomial of the shared `max_degree_bound`, so that the resulting
+/// codeword's low-degreeness can be checked by a single FRI run.
+///
+/// # Panics
+/// Panics if `evaluations` is empty, if `degree_bounds.len() != evaluations.len()`, or if some
+/// `degree_bounds[i] > max_degree_bound`.
+pub fn combine_poly_evaluations_with_degree_bounds<E: FieldElement + StarkField>(
+    evaluations: &Vec<Vec<E>>,
+    degree_bounds: &[usize],
+    max_degree_bound: usize,
+    domain_offset: E::BaseField,
+    batched_fri_challenge: E,
+) -> Vec<E> {
+    assert!(evaluations.len() > 0, "Number of evaluation vectors must be at least 1");
+    assert_eq!(
+        evaluations.len(), degree_bounds.len(),
+        "a degree bound must be supplied for every evaluation vector"
+    );
+    assert!(
+        degree_bounds.iter().all(|&d| d <= max_degree_bound),
+        "every polynomial's degree bound must not exceed the shared max_degree_bound"
+    );
+
+    let eval_vec_size = evaluations[0].len();
+    let num_poly = evaluations.len();
+    let powers: Vec<E> = core::iter::successors(Some(E::ONE), |&p| Some(p * batched_fri_challenge))
+        .take(num_poly)
+        .collect();
+    let corrections: Vec<u64> = degree_bounds.iter().map(|&d| (max_degree_bound - d) as u64).collect();
+    let g = E::BaseField::get_root_of_unity(eval_vec_size.ilog2());
+
+    let combine_position = |j: usize| {
+        let x = E::from(domain_offset * g.exp((j as u64).into()));
+        let mut combined_entry = E::ZERO;
+        for i in 0..num_poly {
+            combined_entry += powers[i] * evaluations[i][j] * x.exp(corrections[i].into());
+        }
+        combined_entry
+    };
+
+    #[cfg(feature = "concurrent")]
+    let combined_evaluations = (0..eval_vec_size).into_par_iter().map(combine_position).collect();
+    #[cfg(not(feature = "concurrent"))]
+    let combined_evaluations = (0..eval_vec_size).map(combine_position).collect();
+
     combined_evaluations
 }
 
+/// Returns the indices of `inputs` sorted by decreasing evaluation-vector length, so that the
+/// polynomial with the largest degree bound comes first.
+fn sort_by_degree_bound<E: FieldElement>(inputs: &[Vec<E>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..inputs.len()).collect();
+    order.sort_by(|&a, &b| inputs[b].len().cmp(&inputs[a].len()));
+    order
+}
+
 
 /// Builds a single proof layer by querying the evaluations of the passed in FRI layer at the
 /// specified positions.
@@ -1,15 +1,18 @@
 use alloc::vec::Vec;
 
-use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, ElementHasher, MerkleTree, RandomCoin};
 use math::{fft, fields::f128::BaseElement, FieldElement};
 use rand_utils::rand_vector;
 use utils::{Deserializable, Serializable, SliceReader};
 use super::{BatchedFriProver, combine_poly_evaluations};
 
+use crate::transcript::RandomCoinTranscript;
 use crate::{
     verifier::DefaultVerifierChannel, BatchedFriProof, BatchedFriVerifier, FriOptions, VerifierError
 };
 
+type Transcript = RandomCoinTranscript<BaseElement, Blake3, DefaultRandomCoin<Blake3>>;
+
 type Blake3 = Blake3_256<BaseElement>;
 
 // PROVE/VERIFY TEST
@@ -54,6 +57,278 @@ fn test_batched_fri_complete_folding() {
     assert!(result.is_ok(), "{:}", result.err().unwrap()); 
 }
 
+#[test]
+fn test_batched_fri_with_grinding() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let max_remainder_degree = 7;
+    let num_polys = 4;
+    let num_queries = 50;
+    let grinding_factor = 8;
+
+    let result = fri_prove_verify_random_with_grinding(
+        trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree, num_polys, num_queries, grinding_factor);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_batched_fri_zero_knowledge() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let max_remainder_degree = 7;
+    let num_polys = 4;
+    let num_queries = 50;
+
+    let degree_bound = 1 << trace_length_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let domain_size = lde_blowup * degree_bound;
+
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+    let mut inputs = Vec::with_capacity(num_polys);
+    for _ in 0..num_polys {
+        inputs.push(build_evaluations_from_random_poly(degree_bound, lde_blowup));
+    }
+
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::new(options.clone(), RandomCoinTranscript::new());
+    let batched_fri_proof = prover.build_proof_zk(inputs, domain_size, num_queries, degree_bound - 1);
+
+    let mut proof_bytes = Vec::new();
+    batched_fri_proof.write_into(&mut proof_bytes);
+    let mut reader = SliceReader::new(&proof_bytes);
+    let batched_fri_proof = BatchedFriProof::read_from(&mut reader).unwrap();
+
+    // The verifier needs no knowledge that every committed polynomial was blinded before being
+    // committed: it sees one function commitment per input, just as in
+    // test_batched_fri_multiple_polynomials, and verifies them exactly the same way.
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, options, degree_bound).unwrap();
+
+    let result = verifier.verify(&batched_fri_proof);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_batched_fri_heterogeneous_degree_bounds() {
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let max_remainder_degree = 7;
+    let num_queries = 50;
+
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let max_degree_bound = 1 << 12;
+    let domain_size = lde_blowup * max_degree_bound;
+
+    // Three polynomials sharing a single domain but with differing degree bounds.
+    let degree_bounds = Vec::from([max_degree_bound, max_degree_bound / 2, max_degree_bound / 4]);
+    let mut inputs = Vec::with_capacity(degree_bounds.len());
+    for &degree_bound in degree_bounds.iter() {
+        inputs.push(build_evaluations_from_random_poly(degree_bound, domain_size / degree_bound));
+    }
+
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::new(options.clone(), RandomCoinTranscript::new());
+    let batched_fri_proof = prover.build_proof_with_degree_bounds(&inputs, &degree_bounds, domain_size, num_queries);
+
+    let mut proof_bytes = Vec::new();
+    batched_fri_proof.write_into(&mut proof_bytes);
+    let mut reader = SliceReader::new(&proof_bytes);
+    let batched_fri_proof = BatchedFriProof::read_from(&mut reader).unwrap();
+
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new_with_degree_bounds(public_coin, num_queries, options, degree_bounds).unwrap();
+
+    let result = verifier.verify(&batched_fri_proof);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_batched_fri_grinding_rejects_insufficient_pow() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let max_remainder_degree = 7;
+    let num_polys = 4;
+    let num_queries = 50;
+
+    let degree_bound = 1 << trace_length_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let domain_size = lde_blowup * degree_bound;
+
+    // Prove without grinding, so the nonce carried by the proof is never searched for.
+    let prove_options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+    let mut inputs = Vec::with_capacity(num_polys);
+    for _ in 0..num_polys {
+        inputs.push(build_evaluations_from_random_poly(degree_bound, lde_blowup));
+    }
+
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::new(prove_options.clone(), RandomCoinTranscript::new());
+    let batched_fri_proof = prover.build_proof(&mut inputs, domain_size, num_queries);
+
+    let mut proof_bytes = Vec::new();
+    batched_fri_proof.write_into(&mut proof_bytes);
+    let mut reader = SliceReader::new(&proof_bytes);
+    let batched_fri_proof = BatchedFriProof::read_from(&mut reader).unwrap();
+
+    // Verify against options that demand a non-trivial grinding factor; since the prover never
+    // searched for a nonce satisfying it, the verifier must reject the proof before it even
+    // samples query positions.
+    let verify_options = prove_options.with_grinding_factor(20);
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, verify_options, degree_bound).unwrap();
+
+    let result = verifier.verify(&batched_fri_proof);
+    assert!(
+        matches!(result, Err(VerifierError::ProofOfWorkVerificationFailed)),
+        "verification should reject a proof whose nonce does not satisfy the required grinding factor"
+    );
+}
+
+#[test]
+fn test_batched_fri_verify_aggregated() {
+    let trace_length_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let max_remainder_degree = 7;
+    let num_polys_per_proof = 3;
+    let num_proofs = 3;
+    let num_queries = 50;
+
+    let degree_bound = 1 << trace_length_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let domain_size = lde_blowup * degree_bound;
+
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+
+    let inputs_per_proof: Vec<Vec<Vec<BaseElement>>> = (0..num_proofs)
+        .map(|_| {
+            (0..num_polys_per_proof)
+                .map(|_| build_evaluations_from_random_poly(degree_bound, lde_blowup))
+                .collect()
+        })
+        .collect();
+
+    let mut provers: Vec<BatchedFriProver<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>> = (0..num_proofs)
+        .map(|_| BatchedFriProver::new(options.clone(), RandomCoinTranscript::new()))
+        .collect();
+
+    // First phase: commit to every proof's function layers, so their commitments can be absorbed
+    // into a shared transcript before deriving the aggregation challenge and query positions.
+    let all_function_commitments: Vec<_> = provers
+        .iter_mut()
+        .zip(inputs_per_proof.iter())
+        .map(|(prover, inputs)| prover.commit_aggregated_function_layers(inputs))
+        .collect();
+
+    // Independently derive the aggregation challenge and query positions exactly as
+    // BatchedFriVerifier::verify_aggregated will, so the proofs built below are consistent with
+    // what that method expects.
+    let mut setup_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    for commitments in all_function_commitments.iter() {
+        for commitment in commitments.iter() {
+            setup_coin.reseed(*commitment);
+        }
+    }
+    let gamma: BaseElement = setup_coin.draw().unwrap();
+    let mut query_positions = setup_coin.draw_integers(num_queries, domain_size, 0).unwrap();
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
+    // Second phase: finish building each proof using the shared challenge and query positions,
+    // with each proof's polynomials continuing the previous proofs' sequence of challenge powers.
+    let mut exponent_offset = 0;
+    let mut proofs = Vec::with_capacity(num_proofs);
+    for (prover, inputs) in provers.iter_mut().zip(inputs_per_proof.iter()) {
+        let proof = prover.build_proof_for_aggregation(inputs, domain_size, gamma, exponent_offset, &query_positions);
+        exponent_offset += inputs.len();
+
+        let mut proof_bytes = Vec::new();
+        proof.write_into(&mut proof_bytes);
+        let mut reader = SliceReader::new(&proof_bytes);
+        proofs.push(BatchedFriProof::read_from(&mut reader).unwrap());
+    }
+
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, options, degree_bound).unwrap();
+
+    let result = verifier.verify_aggregated(&proofs);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_combined_function_layer_single_tree_opening() {
+    use crypto::VectorCommitment;
+    use super::BatchedFriProverChannel;
+
+    let degree_bound = 1 << 8;
+    let lde_blowup = 1 << 2;
+    let num_polys = 6;
+    let domain_size = degree_bound * lde_blowup;
+
+    let mut inputs = Vec::with_capacity(num_polys);
+    for _ in 0..num_polys {
+        inputs.push(build_evaluations_from_random_poly(degree_bound, lde_blowup));
+    }
+
+    let mut channel = BatchedFriProverChannel::<BaseElement, Blake3, Transcript>::new(RandomCoinTranscript::new());
+    let (commitment, transposed) = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::build_combined_function_layer::<2>(&mut channel, &inputs);
+
+    // A single combined commitment should have been pushed, rather than `num_polys` of them.
+    assert_eq!(channel.function_commitments().len(), 1, "combined function layer should push exactly one root");
+
+    let positions = Vec::from([0usize, 3, 7, 10]);
+    let (queried_values, opening_proof) = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::compute_combined_batching_proof::<2>(&commitment, &transposed, &positions, domain_size);
+
+    let folded_positions = crate::folding::fold_positions(&positions, domain_size, 2);
+    let hashed_leaves: Vec<_> = queried_values
+        .iter()
+        .map(|row| {
+            let mut flat = Vec::with_capacity(num_polys * 2);
+            for seg in row {
+                flat.extend_from_slice(seg);
+            }
+            Blake3::hash_elements(&flat)
+        })
+        .collect();
+
+    assert!(
+        MerkleTree::<Blake3>::verify_many(commitment.commitment(), &folded_positions, &hashed_leaves, &opening_proof).is_ok(),
+        "combined opening proof should verify against the single combined root"
+    );
+}
+
+#[test]
+fn test_combine_poly_evaluations_heterogeneous() {
+    use super::combine_poly_evaluations_heterogeneous;
+
+    // Two polynomials: the first has 8 evaluations, the second only 4 (half the domain size).
+    let eval_vec1 = Vec::from([1, 2, 3, 4, 5, 6, 7, 8].map(BaseElement::new));
+    let eval_vec2 = Vec::from([10, 20, 30, 40].map(BaseElement::new));
+    let inputs = Vec::from([eval_vec1.clone(), eval_vec2.clone()]);
+
+    let batched_fri_challenge = BaseElement::new(2);
+
+    // Folding halves the codeword by summing its two halves together, mirroring a
+    // folding-factor-2 degree-respecting projection closely enough to exercise injection.
+    let fold = |evaluations: &mut Vec<BaseElement>| {
+        let half = evaluations.len() / 2;
+        let folded = (0..half).map(|i| evaluations[i] + evaluations[i + half]).collect();
+        *evaluations = folded;
+    };
+
+    let result = combine_poly_evaluations_heterogeneous(&inputs, batched_fri_challenge, fold);
+
+    // eval_vec1 folded once: [1+5, 2+6, 3+7, 4+8] = [6, 8, 10, 12]
+    // then eval_vec2 is injected scaled by challenge^1 = 2: [6 + 20, 8 + 40, 10 + 60, 12 + 80]
+    let expected = Vec::from([26, 48, 70, 92].map(BaseElement::new));
+    assert_eq!(result, expected, "heterogeneous combination did not inject at the expected layer");
+}
+
 #[test]
 fn test_combine_poly_evaluations() {
 
@@ -138,7 +413,7 @@ fn fri_prove_verify_random(
     }
 
     // instantiate the prover and generate the proof
-    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, DefaultRandomCoin<Blake3>>::new(options.clone());
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::new(options.clone(), RandomCoinTranscript::new());
     let batched_fri_proof = prover.build_proof(&mut inputs, domain_size, num_queries);
 
     // test proof serialization / deserialization
@@ -153,3 +428,40 @@ fn fri_prove_verify_random(
     let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, options, degree_bound)?;
     verifier.verify(&batched_fri_proof)
 }
+
+/// Same as [fri_prove_verify_random] but additionally configures a non-zero grinding factor so
+/// that the proof-of-work nonce search is exercised end-to-end.
+fn fri_prove_verify_random_with_grinding(
+    degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    max_remainder_degree: usize,
+    num_poly: usize,
+    num_queries: usize,
+    grinding_factor: u32,
+) -> Result<(), VerifierError> {
+    let degree_bound = 1 << degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let domain_size = lde_blowup * degree_bound;
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree)
+        .with_grinding_factor(grinding_factor);
+
+    let mut inputs = Vec::with_capacity(num_poly);
+    for _ in 0..num_poly {
+        inputs.push(build_evaluations_from_random_poly(degree_bound, lde_blowup));
+    }
+
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, Transcript>::new(options.clone(), RandomCoinTranscript::new());
+    let batched_fri_proof = prover.build_proof(&mut inputs, domain_size, num_queries);
+
+    let mut proof_bytes = Vec::new();
+    batched_fri_proof.write_into(&mut proof_bytes);
+
+    let mut reader = SliceReader::new(&proof_bytes);
+    let batched_fri_proof = BatchedFriProof::read_from(&mut reader).unwrap();
+
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = BatchedFriVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, options, degree_bound)?;
+    verifier.verify(&batched_fri_proof)
+}
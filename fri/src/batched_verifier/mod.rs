@@ -1,12 +1,16 @@
 use core::marker::PhantomData;
 
+use alloc::collections::BTreeMap;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use math::FieldElement;
+use math::{FieldElement, StarkField};
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
 use utils::group_slice_elements;
 
 use crate::folding::fold_positions;
+use crate::transcript::verify_grinding;
 use crate::{BatchedFriProof, DefaultVerifierChannel, FriOptions, FriProofLayer, FriVerifier, VerifierChannel, VerifierError};
 use super::batched_prover::combine_poly_evaluations;
 
@@ -23,6 +27,11 @@ where
 {
     public_coin: R,
     degree_bound: usize,
+    /// Per-polynomial degree bounds set by [new_with_degree_bounds](Self::new_with_degree_bounds),
+    /// `None` when every polynomial shares `degree_bound` (the [new](Self::new) case). When
+    /// `Some`, `degree_bound` holds the largest entry rather than a bound shared by every
+    /// polynomial.
+    poly_degree_bounds: Option<Vec<usize>>,
     domain_size: usize,
     num_queries: usize,
     options: FriOptions,
@@ -33,7 +42,7 @@ where
 
 impl<E, C, H, R, V> BatchedFriVerifier<E, C, H, R, V>
 where
-    E: FieldElement,
+    E: FieldElement + StarkField,
     C: VerifierChannel<E, Hasher = H, VectorCommitment = V>,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
@@ -48,6 +57,7 @@ where
         Ok(BatchedFriVerifier {
             public_coin,
             degree_bound,
+            poly_degree_bounds: None,
             domain_size: options.blowup_factor() * degree_bound.next_power_of_two(),
             num_queries,
             options,
@@ -58,6 +68,38 @@ where
 
     }
 
+    /// Variant of [new](Self::new) for batching polynomials that share a single domain size but
+    /// have differing degree bounds, given by `degree_bounds` (one entry per polynomial to be
+    /// batched, same order they will be passed to [verify](Self::verify)). The shared domain size
+    /// is derived from the largest entry of `degree_bounds`, exactly like [new](Self::new) derives
+    /// it from its single `degree_bound`.
+    ///
+    /// # Errors
+    /// Returns an error if `degree_bounds` is empty.
+    pub fn new_with_degree_bounds(
+        public_coin: R,
+        num_queries: usize,
+        options: FriOptions,
+        degree_bounds: Vec<usize>,
+    ) -> Result<Self, VerifierError> {
+        let max_degree_bound = *degree_bounds
+            .iter()
+            .max()
+            .ok_or(VerifierError::InvalidPolynomialBatching)?;
+
+        Ok(BatchedFriVerifier {
+            public_coin,
+            degree_bound: max_degree_bound,
+            domain_size: options.blowup_factor() * max_degree_bound.next_power_of_two(),
+            poly_degree_bounds: Some(degree_bounds),
+            num_queries,
+            options,
+            _channel: PhantomData,
+            _vector_com: PhantomData,
+            _field_element: PhantomData
+        })
+    }
+
     fn folding_factor(&self) -> usize {
         self.options.folding_factor()
     }
@@ -88,8 +130,20 @@ where
             self.degree_bound - 1
         )?;
 
-        // Sample the query positions using Fiat-Shamir.
-        // TODO: consider using grinding?
+        // If the prover performed proof-of-work grinding, reseed the coin with the claimed nonce
+        // and reject the proof unless it actually satisfies the leading-zero requirement. This
+        // must happen before the query positions are sampled, since the nonce is what binds the
+        // grinding work to the positions that get drawn next.
+        let grinding_factor = self.options.grinding_factor();
+        let pow_nonce = proof.pow_nonce();
+        if !verify_grinding(&mut self.public_coin, grinding_factor, pow_nonce) {
+            return Err(VerifierError::ProofOfWorkVerificationFailed);
+        }
+
+        // Sample the query positions using Fiat-Shamir. The coin was already reseeded with
+        // `pow_nonce` by `verify_grinding` above, so draw with a nonce of 0 here -- passing
+        // `pow_nonce` again would reseed a second time and desync from the prover, which only
+        // ever reseeds with it once (see `BatchedFriProverChannel::grind_query_seed`).
         let mut query_positions = self.public_coin
             .draw_integers(self.num_queries, self.domain_size, 0)
             .expect("Failed to draw batched FRI query positions");
@@ -109,24 +163,43 @@ where
         let folding_factor = self.folding_factor();
         let (queried_values, opening_proofs) = self.parse_batching_proofs(batching_proofs)?;
 
+        // Built once and reused below by every call that would otherwise recompute
+        // fold_positions and linearly rescan it for every query position.
+        let folded_query_map = FoldedQueryMap::new(&query_positions, self.domain_size, folding_factor)?;
+
         // Verify that the opening proofs for the batched polynomials are valid against their commitments.
         match folding_factor {
-            2 => self.verify_opening_proofs::<2>(&function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            4 => self.verify_opening_proofs::<4>(&function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            8 => self.verify_opening_proofs::<8>(&function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            16 => self.verify_opening_proofs::<16>(&function_commitments, &queried_values, &opening_proofs, &query_positions)?,
+            2 => self.verify_opening_proofs::<2>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+            4 => self.verify_opening_proofs::<4>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+            8 => self.verify_opening_proofs::<8>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+            16 => self.verify_opening_proofs::<16>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
             _ => unimplemented!("folding factor {} is not supported", folding_factor),
         }
-        
-        // Verify that the random linear combination using batched_fri_challenge was computed correctly.
-        verify_batching(
-            &query_positions, 
-            &batched_evaluations, 
-            &queried_values, 
-            batched_fri_challenge, 
-            self.domain_size, 
-            folding_factor)?;
-            
+
+        // Verify that the random linear combination using batched_fri_challenge was computed
+        // correctly. When every batched polynomial shares a single degree bound this is a plain
+        // random linear combination; when `poly_degree_bounds` was supplied via
+        // `new_with_degree_bounds`, each polynomial's evaluations must first be degree-corrected
+        // up to the shared `degree_bound` before being combined.
+        match &self.poly_degree_bounds {
+            None => verify_batching(
+                &batched_evaluations,
+                &queried_values,
+                &folded_query_map,
+                batched_fri_challenge,
+                None)?,
+            Some(poly_degree_bounds) => verify_batching_with_degree_bounds(
+                &query_positions,
+                &batched_evaluations,
+                &queried_values,
+                &folded_query_map,
+                poly_degree_bounds,
+                self.degree_bound,
+                batched_fri_challenge,
+                E::BaseField::GENERATOR,
+                self.domain_size)?,
+        }
+
         Ok(())
     }
 
@@ -148,15 +221,140 @@ where
     }
 
 
-    fn verify_opening_proofs<const N: usize>(&self, function_commitments: &[H::Digest], queried_values: &Vec<Vec<E>>, opening_proofs: &Vec<V::MultiProof>, query_positions: &[usize]) -> Result<(), VerifierError> {
+    /// Verifies several independent [BatchedFriProof]s — all over this verifier's own
+    /// `domain_size`/`folding_factor` — more cheaply than calling [verify](Self::verify) once per
+    /// proof.
+    ///
+    /// Each proof was generated against a transcript seeded only with its own function
+    /// commitments, so its own batched FRI challenge, query positions, and FRI low-degree proof
+    /// cannot be merged into a single shared FRI instance after the fact: this method still
+    /// re-derives each proof's own challenge from a transcript reseeded with only that proof's own
+    /// function commitments, and still verifies each proof's own FRI low-degree proof and
+    /// function-layer opening proofs individually.
+    ///
+    /// What this method does merge into a single pass is the random-linear-combination
+    /// consistency check [verify_batching] performs per proof: after reseeding this verifier's own
+    /// public coin with every proof's function commitments and drawing one fresh aggregation
+    /// challenge `gamma`, every proof's claimed batched evaluations are summed and checked in one
+    /// shot against the random linear combination, using `gamma`, of every polynomial across every
+    /// proof concatenated together — the union-of-all-polynomials batch described by
+    /// [build_proof_for_aggregation](crate::BatchedFriProver::build_proof_for_aggregation), which
+    /// proofs verified this way must have been built with. This is the standard randomized batch-
+    /// verification trick: since `gamma` is unknown to the prover ahead of time, a single proof's
+    /// batching being wrong still causes the merged check to fail except with probability
+    /// `1 / |E|`.
+    ///
+    /// Proof-of-work grinding is not supported in combination with aggregation (see
+    /// [build_proof_for_aggregation](crate::BatchedFriProver::build_proof_for_aggregation)).
+    ///
+    /// # Errors
+    /// Returns [VerifierError::InvalidPolynomialBatching] if `proofs` is empty, if the proofs
+    /// disagree on the number of FRI layers (a proxy for disagreeing on `domain_size`, since
+    /// `folding_factor` is already fixed by this verifier's own `options`), or if this verifier was
+    /// constructed with a non-zero grinding factor.
+    pub fn verify_aggregated(&mut self, proofs: &[BatchedFriProof<H>]) -> Result<(), VerifierError> {
+        if proofs.is_empty() {
+            return Err(VerifierError::InvalidPolynomialBatching);
+        }
+        if self.options.grinding_factor() > 0 {
+            return Err(VerifierError::InvalidPolynomialBatching);
+        }
+
+        let expected_num_layers = proofs[0].layer_commitments().len();
+        if proofs.iter().any(|p| p.layer_commitments().len() != expected_num_layers) {
+            return Err(VerifierError::InvalidPolynomialBatching);
+        }
+
+        // Bind every proof's function commitments into this verifier's own transcript before
+        // drawing the single challenge used to aggregate all of the proofs' batching checks.
+        for proof in proofs.iter() {
+            for commitment in proof.function_commitments().iter() {
+                self.public_coin.reseed(*commitment);
+            }
+        }
+        let gamma: E = self.public_coin.draw().expect("Batched FRI verifier failed to draw aggregation challenge.");
+
+        let mut query_positions = self.public_coin
+            .draw_integers(self.num_queries, self.domain_size, 0)
+            .expect("Failed to draw aggregated batched FRI query positions");
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        let folding_factor = self.folding_factor();
+        let mut all_unbatched_evaluations: Vec<Vec<E>> = Vec::new();
+        let mut combined_claimed_evaluations: Vec<E> = vec![E::ZERO; query_positions.len()];
+
+        // Every proof in the batch shares this verifier's own domain_size/folding_factor and the
+        // same query_positions drawn above, so the map built from them is reused for every proof
+        // rather than rebuilt per proof.
+        let folded_query_map = FoldedQueryMap::new(&query_positions, self.domain_size, folding_factor)?;
+
+        for proof in proofs.iter() {
+            // Set up a transcript reseeded only with this proof's own function commitments, so the
+            // FRI-layer folding challenges drawn below during FRI verification start from the same
+            // coin state build_proof_for_aggregation's own channel was in when it folded this
+            // proof's layers (it does not draw a batched FRI challenge of its own, since that value
+            // is supplied externally instead — see build_proof_for_aggregation).
+            let mut local_coin = R::new(&[]);
+            for commitment in proof.function_commitments().iter() {
+                local_coin.reseed(*commitment);
+            }
+
+            let mut channel = DefaultVerifierChannel::<E, H, V>::new(
+                proof.fri_proof().clone(),
+                proof.layer_commitments().to_vec(),
+                self.domain_size,
+                folding_factor,
+            ).unwrap();
+
+            let fri_verifier = FriVerifier::new(&mut channel, &mut local_coin, self.options.clone(), self.degree_bound - 1)?;
+
+            let batched_evaluations = proof.parse_evaluations()?;
+            if batched_evaluations.len() != query_positions.len() {
+                return Err(VerifierError::InvalidPolynomialBatching);
+            }
+            fri_verifier.verify(&mut channel, &batched_evaluations, &query_positions)?;
+
+            let function_commitments = proof.function_commitments();
+            let batching_proofs = proof.batching_proofs().to_vec();
+            let (queried_values, opening_proofs) = self.parse_batching_proofs(batching_proofs)?;
+            match folding_factor {
+                2 => self.verify_opening_proofs::<2>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+                4 => self.verify_opening_proofs::<4>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+                8 => self.verify_opening_proofs::<8>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+                16 => self.verify_opening_proofs::<16>(&function_commitments, &queried_values, &opening_proofs, &folded_query_map)?,
+                _ => unimplemented!("folding factor {} is not supported", folding_factor),
+            }
+
+            let unbatched_evaluations = extract_evaluations_from_map(&queried_values, &folded_query_map);
+            all_unbatched_evaluations.extend(unbatched_evaluations);
+
+            for (acc, &value) in combined_claimed_evaluations.iter_mut().zip(batched_evaluations.iter()) {
+                *acc += value;
+            }
+        }
+
+        // Check every polynomial across every proof's random linear combination in a single pass,
+        // rather than once per proof.
+        let expected_combined_evaluations = combine_poly_evaluations(&all_unbatched_evaluations, gamma);
+        if expected_combined_evaluations != combined_claimed_evaluations {
+            return Err(VerifierError::InvalidPolynomialBatching);
+        }
+
+        Ok(())
+    }
+
+
+    fn verify_opening_proofs<const N: usize>(&self, function_commitments: &[H::Digest], queried_values: &Vec<Vec<E>>, opening_proofs: &Vec<V::MultiProof>, folded_query_map: &FoldedQueryMap) -> Result<(), VerifierError> {
 
         assert_eq!(function_commitments.len(), queried_values.len(), "The number of function commitments does not match the number of queried evaluation vectors.");
         assert_eq!(queried_values.len(), opening_proofs.len(), "The number of queried evaluation vectors does not match the number of opening proofs.");
 
-        let query_positions = fold_positions(query_positions, self.domain_size, self.folding_factor());
-
-        for i in 0..function_commitments.len() {
+        let query_positions = &folded_query_map.folded_positions;
 
+        // Every polynomial's opening proof is checked independently of every other's, so with the
+        // `concurrent` feature enabled this runs over `rayon`'s thread pool.
+        let verify_one = |i: usize| -> Result<(), VerifierError> {
             // build the values (i.e., polynomial evaluations over a coset of a multiplicative subgroup
             // of the current evaluation domain) corresponding to each leaf of the layer commitment
             let leaf_values : &[[E; N]] = group_slice_elements(&queried_values[i]);
@@ -170,14 +368,66 @@ where
 
             V::verify_many(
                 function_commitments[i],
-                &query_positions,
+                query_positions,
                 &hashed_values,
                 &opening_proofs[i],
             )
-            .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
+            .map_err(|_| VerifierError::LayerCommitmentMismatch)
+        };
+
+        #[cfg(feature = "concurrent")]
+        let results: Vec<Result<(), VerifierError>> = (0..function_commitments.len()).into_par_iter().map(verify_one).collect();
+        #[cfg(not(feature = "concurrent"))]
+        let results: Vec<Result<(), VerifierError>> = (0..function_commitments.len()).map(verify_one).collect();
+
+        results.into_iter().collect::<Result<(), VerifierError>>()
+    }
+}
+
+
+/// Precomputes, for one set of `query_positions`, everything [extract_evaluations] and
+/// [BatchedFriVerifier::verify_opening_proofs] need from [fold_positions] so that calling
+/// [verify](BatchedFriVerifier::verify) (or
+/// [verify_aggregated](BatchedFriVerifier::verify_aggregated)) once never calls [fold_positions]
+/// or scans its result more than once per query position, regardless of how many times the
+/// underlying evaluations are extracted.
+struct FoldedQueryMap {
+    /// The deduplicated folded positions, in the order [fold_positions] returned them.
+    folded_positions: Vec<usize>,
+    /// For each entry of `query_positions`, the flattened index — into the transposed
+    /// `queried_values` layout `extract_evaluations` reads from — at which that query's
+    /// evaluation lives.
+    indices: Vec<usize>,
+}
+
+impl FoldedQueryMap {
+    /// Builds the map for `query_positions` over a domain of `domain_size` folded by
+    /// `folding_factor`.
+    ///
+    /// # Errors
+    /// Returns [VerifierError::InvalidPolynomialBatching] if some entry of `query_positions`
+    /// folds to a position [fold_positions] did not return, which can only happen if the proof
+    /// being verified supplied positions inconsistent with `domain_size`/`folding_factor`.
+    fn new(query_positions: &[usize], domain_size: usize, folding_factor: usize) -> Result<Self, VerifierError> {
+        let folded_domain_size = domain_size / folding_factor;
+        let folded_positions = fold_positions(query_positions, domain_size, folding_factor);
+
+        let index_of: BTreeMap<usize, usize> = folded_positions
+            .iter()
+            .enumerate()
+            .map(|(index, &position)| (position, index))
+            .collect();
+
+        let mut indices = Vec::with_capacity(query_positions.len());
+        for &position in query_positions {
+            let folded_position = position % folded_domain_size;
+            let index = index_of
+                .get(&folded_position)
+                .ok_or(VerifierError::InvalidPolynomialBatching)?;
+            indices.push(index * folding_factor + position / folded_domain_size);
         }
-        
-        Ok(())
+
+        Ok(FoldedQueryMap { folded_positions, indices })
     }
 }
 
@@ -185,46 +435,135 @@ where
 // HELPER FUNCTIONS
 // ================================================================================================
 
-pub(crate) fn verify_batching<E: FieldElement>(query_positions: &[usize], batched_evaluations: &[E], queried_values: &Vec<Vec<E>>, batched_fri_challenge: E, domain_size: usize, folding_factor: usize) -> Result<(), VerifierError> {
-
-    // Extract from queried_values which is in transposed form the evaluations of each polynomial 
+/// Checks that `batched_evaluations` is the random linear combination, using
+/// `batched_fri_challenge`, of the per-polynomial evaluations recovered from `queried_values`.
+///
+/// `blinding_evaluations`, when `Some`, is the combined blinding contribution a zero-knowledge
+/// prover added to every polynomial's last layer before batching (see
+/// [FoldingOptions::zk](crate::fold_and_batch_prover::FoldingOptions::zk)), at the same query
+/// positions as `batched_evaluations`; it is subtracted out before the two sides are compared,
+/// so that the prover's random linear combination check still succeeds despite the hiding
+/// blinding baked into `batched_evaluations`.
+pub(crate) fn verify_batching<E: FieldElement>(batched_evaluations: &[E], queried_values: &Vec<Vec<E>>, folded_query_map: &FoldedQueryMap, batched_fri_challenge: E, blinding_evaluations: Option<&[E]>) -> Result<(), VerifierError> {
+
+    // Extract from queried_values which is in transposed form the evaluations of each polynomial
     // at query_positions.
-    let unbatched_evaluations = extract_evaluations(&query_positions, queried_values, domain_size, folding_factor);
+    let unbatched_evaluations = extract_evaluations_from_map(queried_values, folded_query_map);
 
     let expected_batched_evaluations = combine_poly_evaluations(&unbatched_evaluations, batched_fri_challenge);
 
-    if expected_batched_evaluations != batched_evaluations {
+    let matches = match blinding_evaluations {
+        Some(blinding_evaluations) => {
+            assert_eq!(
+                blinding_evaluations.len(),
+                batched_evaluations.len(),
+                "one blinding evaluation must be supplied per queried batched evaluation"
+            );
+            expected_batched_evaluations
+                .iter()
+                .zip(batched_evaluations.iter())
+                .zip(blinding_evaluations.iter())
+                .all(|((expected, &actual), &blinding)| *expected == actual - blinding)
+        }
+        None => expected_batched_evaluations == batched_evaluations,
+    };
+
+    if !matches {
         return Err(VerifierError::InvalidPolynomialBatching)
     }
     Ok(())
 }
 
 
-pub fn extract_evaluations<E: FieldElement>(query_positions: &[usize], queried_values: &Vec<Vec<E>>, domain_size: usize, folding_factor: usize) -> Vec<Vec<E>> {
-    let mut unbatched_evaluations = Vec::with_capacity(queried_values.len());
-
-    let folded_domain_size = domain_size / folding_factor;
-    let folded_positions = fold_positions(query_positions, domain_size, folding_factor);
-    let mut indices = Vec::new();
+/// Like [verify_batching], but for polynomials that share a single domain size while having
+/// differing degree bounds, given by `degree_bounds` (one entry per vector in `queried_values`,
+/// same order). Before the random linear combination is checked, every polynomial's recovered
+/// evaluation is degree-corrected up to `max_degree_bound` by multiplying it by
+/// `x^(max_degree_bound - degree_bounds[i])`, mirroring the correction the prover applies in
+/// [combine_poly_evaluations_with_degree_bounds].
+///
+/// # Errors
+/// Returns [VerifierError::InvalidPolynomialBatching] if some entry of `degree_bounds` exceeds
+/// `max_degree_bound`, or if the random linear combination does not match `batched_evaluations`.
+pub(crate) fn verify_batching_with_degree_bounds<E: FieldElement + StarkField>(
+    query_positions: &[usize],
+    batched_evaluations: &[E],
+    queried_values: &Vec<Vec<E>>,
+    folded_query_map: &FoldedQueryMap,
+    degree_bounds: &[usize],
+    max_degree_bound: usize,
+    batched_fri_challenge: E,
+    domain_offset: E::BaseField,
+    domain_size: usize,
+) -> Result<(), VerifierError> {
+    if degree_bounds.iter().any(|&d| d > max_degree_bound) {
+        return Err(VerifierError::InvalidPolynomialBatching);
+    }
+    if degree_bounds.len() != queried_values.len() {
+        return Err(VerifierError::InvalidPolynomialBatching);
+    }
 
-    for position in query_positions {
-        let folded_position = position % folded_domain_size;
+    // Extract from queried_values which is in transposed form the evaluations of each polynomial
+    // at query_positions.
+    let unbatched_evaluations = extract_evaluations_from_map(queried_values, folded_query_map);
+
+    let powers: Vec<E> = core::iter::successors(Some(E::ONE), |&p| Some(p * batched_fri_challenge))
+        .take(degree_bounds.len())
+        .collect();
+    let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+
+    let expected_batched_evaluations: Vec<E> = query_positions
+        .iter()
+        .enumerate()
+        .map(|(idx, &position)| {
+            let x = E::from(domain_offset * g.exp((position as u64).into()));
+            let mut combined_entry = E::ZERO;
+            for (poly_idx, evals) in unbatched_evaluations.iter().enumerate() {
+                let correction = x.exp(((max_degree_bound - degree_bounds[poly_idx]) as u64).into());
+                combined_entry += powers[poly_idx] * evals[idx] * correction;
+            }
+            combined_entry
+        })
+        .collect();
 
-        // Find the index of folded_position in folded_positions
-        if let Some(index) = folded_positions.iter().position(|&x| x == folded_position) {
-            indices.push(index * folding_factor + position / folded_domain_size);
-        } else {
-            panic!("The folded position {} cannot be found in the folded_positions vector: {:?}", folded_position, folded_positions);
-        }
+    if expected_batched_evaluations != batched_evaluations {
+        return Err(VerifierError::InvalidPolynomialBatching);
     }
+    Ok(())
+}
+
+/// Extracts from `queried_values`, which is in transposed form (one vector per committed
+/// function layer, grouped in folding-factor-sized coset chunks), the evaluation of each
+/// polynomial at every position `folded_query_map` was built from. This is the version of
+/// [extract_evaluations] used internally by [BatchedFriVerifier], which builds one
+/// [FoldedQueryMap] per proof and reuses it across every polynomial being extracted, rather than
+/// recomputing [fold_positions] and rescanning it for every call the way the public, per-call
+/// [extract_evaluations] does.
+fn extract_evaluations_from_map<E: FieldElement>(queried_values: &Vec<Vec<E>>, folded_query_map: &FoldedQueryMap) -> Vec<Vec<E>> {
+    let mut unbatched_evaluations = Vec::with_capacity(queried_values.len());
 
     for eval_vector in queried_values {
-        let mut evaluation_vector = Vec::with_capacity(query_positions.len());
-        for index in indices.iter() {
+        let mut evaluation_vector = Vec::with_capacity(folded_query_map.indices.len());
+        for index in folded_query_map.indices.iter() {
             evaluation_vector.push(eval_vector[*index]);
         }
         unbatched_evaluations.push(evaluation_vector);
     }
 
     unbatched_evaluations
+}
+
+/// Extracts from `queried_values`, which is in transposed form, the evaluations of each
+/// polynomial at `query_positions`. Shared by [fold_and_batch_verifier](crate::fold_and_batch_verifier),
+/// [folding_pcs](crate::folding_pcs), and [fri_pcs](crate::fri_pcs), which each call this once per
+/// proof and so do not need a cached [FoldedQueryMap] the way [BatchedFriVerifier] does.
+///
+/// # Panics
+/// Panics if some entry of `query_positions` folds to a position [fold_positions] did not
+/// return, which can only happen if the caller supplied positions inconsistent with
+/// `domain_size`/`folding_factor`.
+pub fn extract_evaluations<E: FieldElement>(query_positions: &[usize], queried_values: &Vec<Vec<E>>, domain_size: usize, folding_factor: usize) -> Vec<Vec<E>> {
+    let folded_query_map = FoldedQueryMap::new(query_positions, domain_size, folding_factor)
+        .expect("every query position must fold to a position fold_positions returned");
+    extract_evaluations_from_map(queried_values, &folded_query_map)
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::{env, io::Read};
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, Hasher, MerkleTree, RandomCoin};
 use math::fields::{f128::BaseElement, QuadExtension};
 use utils::{Deserializable, SliceReader};
-use winter_fri::{fold_and_batch_master_commit, fold_and_batch_master_query, DefaultProverChannel, FoldingProof, FriOptions, FriProver};
+use winter_fri::{fold_and_batch_master_commit, fold_and_batch_master_query, DefaultProverChannel, FoldAndBatchWorkerOutput, FoldingProof, FriOptions, FriProver};
 
 type Blake3 = Blake3_256<BaseElement>;
 type Blake3Digest = <Blake3 as Hasher>::Digest;
@@ -38,64 +38,34 @@ fn run_distributed_fri_master(circuit_size_e: usize, num_poly_e: usize, mode: Mo
         .draw_integers(NUM_QUERIES, worker_domain_size, 0)
         .expect("failed to draw query positions");
 
-    let evaluations_size = master_domain_size;
     let mut batched_fri_inputs = Vec::with_capacity(num_poly);
     let mut worker_layer_commitments : Vec<Vec<Blake3Digest>> = Vec::with_capacity(num_poly);
     let mut folding_proofs: Vec<FoldingProof> = Vec::with_capacity(num_poly);
     let mut worker_queried_evaluations : Vec<Vec<QuadExtension<BaseElement>>> = Vec::with_capacity(num_poly);
 
-    // Read the master prover inputs from stdin
+    // Read the master prover inputs from stdin. Each of the `num_poly` worker nodes writes a
+    // single versioned `FoldAndBatchWorkerOutput` envelope (see `fold_and_batch_proof.rs`) to its
+    // stdout, and those `num_poly` envelopes are concatenated onto this process's stdin; reading
+    // them back via `read_from` replaces the previous hand-rolled `[u8; 32]`-buffer protocol,
+    // which hard-coded the field element's width and derived the number of worker layers
+    // arithmetically instead of reading it off the wire.
     let mut file = std::io::stdin();
-
-    // Read the batched fri inputs.
-    for _ in 0..num_poly {
-        let mut eval_vec = Vec::with_capacity(evaluations_size);
-        for _ in 0..evaluations_size {
-            let mut buf = [0u8; 32]; 
-            file.read_exact(&mut buf).unwrap();
-            let mut reader = SliceReader::new(&buf);
-            let element = QuadExtension::<BaseElement>::read_from(&mut reader).unwrap();
-            eval_vec.push(element);
-        }
-        batched_fri_inputs.push(eval_vec);
-    }
-
-    // Read the worker layer commitments.
-    let num_worker_layers = (worker_degree_bound / master_degree_bound) / FOLDING_FACTOR + 1;
-    for _ in 0..num_poly {
-        let mut layer_commitment_vec = Vec::with_capacity(num_worker_layers);
-        for _ in 0..num_worker_layers {
-            let mut buf = [0u8; 32]; 
-            file.read_exact(&mut buf).unwrap();
-            let mut reader = SliceReader::new(&buf);
-            layer_commitment_vec.push(Blake3Digest::read_from(&mut reader).unwrap());
-        }
-        worker_layer_commitments.push(layer_commitment_vec);
-    }
-
-    // Read the worker queried evaluations.
-    for _ in 0..num_poly {
-        let mut queried_eval_vec = Vec::with_capacity(NUM_QUERIES);
-        for _ in 0..NUM_QUERIES {
-            let mut buf = [0u8; 32]; 
-            file.read_exact(&mut buf).unwrap();
-            let mut reader = SliceReader::new(&buf);
-            let element = QuadExtension::<BaseElement>::read_from(&mut reader).unwrap();
-            queried_eval_vec.push(element);
-        }
-        worker_queried_evaluations.push(queried_eval_vec);
-    }
-
-    // Read the folding proofs.
-    let mut buf = Vec::<u8>::new(); 
+    let mut buf = Vec::<u8>::new();
     file.read_to_end(&mut buf).unwrap();
     let mut reader = SliceReader::new(&buf);
+
     for _ in 0..num_poly {
-        folding_proofs.push(FoldingProof::read_from(&mut reader).unwrap());
+        let worker_output = FoldAndBatchWorkerOutput::<QuadExtension<BaseElement>, Blake3>::read_from(&mut reader)
+            .expect("failed to read a worker output envelope from stdin");
+        let (batched_fri_input, layer_commitments, folding_proof, queried_evaluations) = worker_output.into_parts();
+        batched_fri_inputs.push(batched_fri_input);
+        worker_layer_commitments.push(layer_commitments);
+        folding_proofs.push(folding_proof);
+        worker_queried_evaluations.push(queried_evaluations);
     }
 
     // check if we've read all the bytes
-    if file.bytes().next().is_some() {
+    if reader.has_more_bytes() {
         panic!("Uncomsumed bytes in the batched fri input file");
     }
 
@@ -1,15 +1,16 @@
-use std::{env, io::Read};
+use std::{env, io::{Read, Write}};
 
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
 use math::fields::{f128::BaseElement, QuadExtension};
-use utils::{Deserializable, SliceReader};
-use winter_fri::{DefaultProverChannel, FoldingOptions, FoldingProver};
+use utils::{Deserializable, Serializable, SliceReader};
+use winter_fri::{DefaultProverChannel, FoldAndBatchWorkerOutput, FoldingOptions, FoldingProver, ProverChannel};
 
 type Blake3 = Blake3_256<BaseElement>;
 
 static BLOWUP_FACTOR: usize = 4;
 static FOLDING_FACTOR: usize = 2;
 static NUM_QUERIES: usize = 282;
+static GRINDING_FACTOR: u32 = 16;
 
 enum Mode {
     DistributedBatchedFri,
@@ -27,16 +28,26 @@ fn run_single_distributed_fri_worker(circuit_size_e: usize, num_poly_e: usize, m
     let worker_domain_size = worker_degree_bound.next_power_of_two() * BLOWUP_FACTOR;
     
     let options = FoldingOptions::new(
-        BLOWUP_FACTOR, 
-        FOLDING_FACTOR, 
-        worker_domain_size, 
-        last_poly_max_degree);
-
-    // Prepare the query positions. For simplicity, we draw some random integers 
+        BLOWUP_FACTOR,
+        FOLDING_FACTOR,
+        worker_domain_size,
+        last_poly_max_degree)
+        .unwrap()
+        .with_grinding_factor(GRINDING_FACTOR);
+
+    // Prepare the query positions. For simplicity, we draw some random integers
     // instead of using Fiat-Shamir.
     let mut public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+
+    // Grind for a proof-of-work nonce before drawing query positions, trading prover time for
+    // the bits of security NUM_QUERIES above would otherwise need to supply alone.
+    let pow_nonce = (1..u64::MAX)
+        .find(|&nonce| public_coin.check_leading_zeros(nonce) >= options.grinding_factor())
+        .expect("failed to find a grinding nonce satisfying the required number of leading zeros");
+    public_coin.reseed_with_int(pow_nonce);
+
     let query_positions = public_coin
-        .draw_integers(NUM_QUERIES, worker_domain_size, 0)
+        .draw_integers(NUM_QUERIES, worker_domain_size, pow_nonce)
         .expect("failed to draw query positions");
 
     let mut prover = FoldingProver::<QuadExtension<BaseElement>, _, _, MerkleTree<Blake3>>::new(options.clone());
@@ -61,9 +72,25 @@ fn run_single_distributed_fri_worker(circuit_size_e: usize, num_poly_e: usize, m
         panic!("Uncomsumed bytes in the batched fri input file");
     }
 
-    let _ = prover.build_layers(&mut channel, evaluations.clone());
-    let _ = prover.build_proof(&evaluations, &query_positions);
-    
+    let batched_fri_input = prover.build_layers(&mut channel, evaluations.clone());
+    let layer_commitments = channel.layer_commitments().to_vec();
+    let (folding_proof, queried_evaluations) = prover.build_proof(&evaluations, &query_positions, pow_nonce);
+
+    // Bundle this worker's output into a single versioned envelope and write it to stdout, where
+    // it is picked up by the master (see `distributed_fri_master.rs`). Using the envelope here
+    // (rather than writing each piece as a raw, fixed-size byte buffer) means the worker and the
+    // master can evolve independently: a field, hasher, or wire-format mismatch is caught by
+    // `FoldAndBatchWorkerOutput::read_from` instead of silently misparsing the stream.
+    let worker_output = FoldAndBatchWorkerOutput::<QuadExtension<BaseElement>, Blake3>::new(
+        batched_fri_input,
+        layer_commitments,
+        folding_proof,
+        queried_evaluations,
+    );
+
+    let mut bytes = Vec::new();
+    worker_output.write_into(&mut bytes);
+    std::io::stdout().write_all(&bytes).expect("failed to write worker output to stdout");
 }
 
 
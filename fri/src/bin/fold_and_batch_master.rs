@@ -3,7 +3,10 @@ use std::{env, fs::File};
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
 use math::fields::f128::BaseElement;
 use utils::{ByteReader, Deserializable, ReadAdapter};
-use winter_fri::{fold_and_batch_prove, BatchedFriProver, DefaultProverChannel, FoldingOptions, FoldingProver, FriOptions, FriProver};
+use winter_fri::{
+    fold_and_batch_prove, transcript::RandomCoinTranscript, BatchedFriProver, DefaultProverChannel, FoldingOptions,
+    FoldingProver, FriOptions, FriProver,
+};
 
 type Blake3 = Blake3_256<BaseElement>;
 
@@ -40,7 +43,10 @@ fn run_fold_and_batch_master(circuit_size_e: usize, num_poly_e: usize) {
     }
 
     // instantiate the prover and generate the proof
-    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, DefaultRandomCoin<Blake3>>::new(master_options);
+    let mut prover = BatchedFriProver::<BaseElement, Blake3, MerkleTree<Blake3>, RandomCoinTranscript<BaseElement, Blake3, DefaultRandomCoin<Blake3>>>::new(
+        master_options,
+        RandomCoinTranscript::new(),
+    );
     let _ = prover.build_proof(&mut inputs, master_domain_size, NUM_QUERIES);
 }
 
@@ -10,6 +10,7 @@ type Blake3 = Blake3_256<BaseElement>;
 static BLOWUP_FACTOR: usize = 8;
 static FOLDING_FACTOR: usize = 2;
 static NUM_QUERIES: usize = 50;
+static GRINDING_FACTOR: u32 = 16;
 
 
 fn run_single_fold_and_batch_worker(circuit_size_e: usize, num_poly_e: usize) {
@@ -20,16 +21,26 @@ fn run_single_fold_and_batch_worker(circuit_size_e: usize, num_poly_e: usize) {
     let worker_domain_size = worker_degree_bound.next_power_of_two() * BLOWUP_FACTOR;
     
     let options = FoldingOptions::new(
-        BLOWUP_FACTOR, 
-        FOLDING_FACTOR, 
-        worker_domain_size, 
-        last_poly_max_degree);
+        BLOWUP_FACTOR,
+        FOLDING_FACTOR,
+        worker_domain_size,
+        last_poly_max_degree)
+        .unwrap()
+        .with_grinding_factor(GRINDING_FACTOR);
 
-    // Prepare the query positions. For simplicity, we draw some random integers 
+    // Prepare the query positions. For simplicity, we draw some random integers
     // instead of using Fiat-Shamir.
     let mut public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+
+    // Grind for a proof-of-work nonce before drawing query positions, trading prover time for
+    // the bits of security NUM_QUERIES above would otherwise need to supply alone.
+    let pow_nonce = (1..u64::MAX)
+        .find(|&nonce| public_coin.check_leading_zeros(nonce) >= options.grinding_factor())
+        .expect("failed to find a grinding nonce satisfying the required number of leading zeros");
+    public_coin.reseed_with_int(pow_nonce);
+
     let query_positions = public_coin
-        .draw_integers(NUM_QUERIES, worker_domain_size, 0)
+        .draw_integers(NUM_QUERIES, worker_domain_size, pow_nonce)
         .expect("failed to draw query positions");
 
     let mut prover = FoldingProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
@@ -47,7 +58,7 @@ fn run_single_fold_and_batch_worker(circuit_size_e: usize, num_poly_e: usize) {
     }
 
     let _ = prover.build_layers(&mut channel, evaluations.clone());
-    let _ = prover.build_proof(&evaluations, &query_positions);
+    let _ = prover.build_proof(&evaluations, &query_positions, pow_nonce);
     
 }
 
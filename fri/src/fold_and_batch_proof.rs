@@ -1,39 +1,130 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use core::any::type_name;
 use crypto::ElementHasher;
 use math::FieldElement;
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader};
 use crate::{FriProof, FriProofLayer, VerifierError};
 
+// LEB128 ENCODING
+// ================================================================================================
+//
+// [FoldingProof] and [FoldAndBatchProof] both serialize several vectors whose length is not
+// bounded by a single worker node or a single computation's trace length (e.g. the number of
+// workers in a cluster, or the byte length of an evaluation vector), so a fixed-width length
+// prefix either truncates on overflow (a `u8`/`u16` count silently wrapping) or wastes space on
+// the common case of a small count (a `u32` prefix for what is usually a handful of workers). A
+// LEB128 length prefix -- 7 data bits per byte, with the high bit set on every byte but the last
+// -- stays a single byte for any count under 128 while still being able to represent an
+// arbitrarily large one.
+
+/// Returns the number of bytes [write_leb128] would write for `value`.
+fn leb128_size(mut value: usize) -> usize {
+    let mut size = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        size += 1;
+    }
+    size
+}
+
+/// Writes `value` to `target` as a LEB128-encoded unsigned integer.
+fn write_leb128<W: ByteWriter>(target: &mut W, mut value: usize) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            target.write_u8(byte);
+            return;
+        }
+        target.write_u8(byte | 0x80);
+    }
+}
+
+/// Reads a LEB128-encoded unsigned integer from `source`.
+///
+/// # Errors
+/// Returns a [DeserializationError] if `source` runs out of bytes before a terminating byte (high
+/// bit clear) is read, or if the encoded value does not fit in a `usize` on this platform.
+fn read_leb128<R: ByteReader>(source: &mut R) -> Result<usize, DeserializationError> {
+    let mut result: usize = 0;
+    let mut shift: u32 = 0;
+    loop {
+        if shift >= usize::BITS {
+            return Err(DeserializationError::InvalidValue(
+                "LEB128-encoded length does not fit in this platform's usize".to_string(),
+            ));
+        }
+        let byte = source.read_u8()?;
+        result |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
 #[derive(Clone)]
 pub struct FoldingProof
 {
-    folding_proof: Vec<FriProofLayer>
+    folding_proof: Vec<FriProofLayer>,
+    folding_schedule: Vec<u8>,
+    pow_nonce: u64,
 }
 
 impl FoldingProof
 {
-    pub fn new(folding_proof: Vec<FriProofLayer>) -> Self {
-        assert!(!folding_proof.is_empty(), "The folding proof must contain at least one FriProofLayer");
-        FoldingProof { folding_proof }
+    /// Returns a new [FoldingProof] wrapping `folding_proof`'s per-layer openings, alongside the
+    /// folding arity used at each layer.
+    ///
+    /// `folding_proof` may be empty: a worker whose local polynomial folds down to its last
+    /// layer in a single step commits no intermediate layers at all, since that last layer is
+    /// committed by the master, as that worker's own function commitment, rather than by the worker.
+    ///
+    /// `pow_nonce` is the proof-of-work nonce found (if [FoldingOptions::grinding_factor](crate::FoldingOptions::grinding_factor)
+    /// is non-zero) just before this prover's own query positions were drawn; it is 0 when no
+    /// query positions are sampled from this proof's own transcript, as in the Fold-and-Batch
+    /// protocol where a worker's query positions are instead folded down from the master's.
+    ///
+    /// # Panics
+    /// Panics if `folding_schedule` does not have exactly one entry per layer in `folding_proof`.
+    pub fn new(folding_proof: Vec<FriProofLayer>, folding_schedule: Vec<u8>, pow_nonce: u64) -> Self {
+        assert_eq!(
+            folding_proof.len(),
+            folding_schedule.len(),
+            "the folding schedule must declare exactly one arity per layer"
+        );
+        FoldingProof { folding_proof, folding_schedule, pow_nonce }
     }
 
     pub fn folding_proof(&self) -> &Vec<FriProofLayer> {
         &self.folding_proof
     }
 
-    pub fn batching_proof(&self) -> &FriProofLayer {
-        self.folding_proof.last().unwrap()
+    /// Returns the folding arity used at each layer, in commit order, e.g. `[2, 2, 4]` for a
+    /// worker that folds by 2 for its first two layers and by 4 for its third.
+    pub fn folding_schedule(&self) -> &Vec<u8> {
+        &self.folding_schedule
+    }
+
+    /// Returns the proof-of-work nonce found before this proof's own query positions were drawn.
+    pub fn pow_nonce(&self) -> u64 {
+        self.pow_nonce
     }
 
     // Returns the number of bytes in this folding proof.
     pub fn size(&self) -> usize {
-        // + 1 for the length of the folding_proof vector
-        self.folding_proof.iter().fold(1, |acc, layer| acc + layer.size())
+        // + the LEB128-encoded length of the folding_proof vector, + 1 byte per layer for the
+        // schedule (which reuses that same length, since it carries one arity per layer), + 8
+        // for the pow_nonce.
+        self.folding_proof
+            .iter()
+            .fold(leb128_size(self.folding_proof.len()) + self.folding_schedule.len() + 8, |acc, layer| acc + layer.size())
     }
 }
 
+#[derive(Clone)]
 pub struct FoldAndBatchProof<H>
-where 
+where
     H: ElementHasher,
 {
     folding_proofs: Vec<FoldingProof>,
@@ -42,12 +133,42 @@ where
     master_evaluations: Vec<u8>,
     worker_layer_commitments: Vec<Vec<H::Digest>>,
     master_layer_commitments: Vec<H::Digest>,
-} 
+    function_commitments: Vec<H::Digest>,
+    function_openings: Vec<FriProofLayer>,
+    blinding_evaluations: Vec<u8>,
+    master_remainder_points: Vec<u8>,
+    master_remainder_evaluations: Vec<u8>,
+    worker_evaluation_parity: Vec<Vec<u8>>,
+    worker_evaluation_parity_points: Vec<u8>,
+    worker_remainder_coefficients: Vec<Vec<u8>>,
+    pow_nonce: u64,
+}
 
 impl<H> FoldAndBatchProof<H>
 where
     H: ElementHasher,
 {
+    /// Returns a new [FoldAndBatchProof].
+    ///
+    /// `worker_layer_commitments` holds only the *intermediate* layer commitments of each worker
+    /// (and may be empty for a worker whose local polynomial is folded down to its last layer in
+    /// a single step): every worker's last layer is instead committed by the master, one
+    /// commitment per worker, in `function_commitments`, opened at the queried positions by the
+    /// matching entry of `function_openings`, rather than batching every worker's last layer into
+    /// a single combined tree as when every worker shares the same ending domain size. This lets
+    /// workers end their local folding at differing degrees (see
+    /// [fold_and_batch_worker_commit](crate::fold_and_batch_prover::fold_and_batch_worker_commit))
+    /// without requiring every worker's last layer to be opened at the same domain size, at the
+    /// cost of one opening proof per worker instead of a single opening for the whole batch.
+    /// `blinding_evaluations` is the combined per-position blinding contribution the master
+    /// added to every worker's last layer before batching, evaluated at the same query
+    /// positions as `master_evaluations`; the verifier subtracts it before checking the batched
+    /// FRI random linear combination. It is empty when the proof was built without zero-
+    /// knowledge hiding enabled.
+    ///
+    /// # Panics
+    /// Panics if `folding_proofs.len()` does not equal `worker_layer_commitments.len()`, or if
+    /// `function_commitments.len()` does not equal `function_openings.len()`.
     pub(crate) fn new<E: FieldElement>(
         folding_proofs: Vec<FoldingProof>,
         fri_proof: FriProof,
@@ -55,9 +176,14 @@ where
         master_evaluations: Vec<E>,
         worker_layer_commitments: Vec<Vec<H::Digest>>,
         master_layer_commitments: Vec<H::Digest>,
+        function_commitments: Vec<H::Digest>,
+        function_openings: Vec<FriProofLayer>,
+        blinding_evaluations: Vec<E>,
+        pow_nonce: u64,
     ) -> Self {
         assert_eq!(folding_proofs.len(), worker_layer_commitments.len(), "The number of folding proofs should match the number of layer commitment vectors");
-        
+        assert_eq!(function_commitments.len(), function_openings.len(), "every function commitment must have a matching opening");
+
         // Convert master evaluations into a vector of bytes
         let mut master_evaluations_bytes = Vec::with_capacity(E::ELEMENT_BYTES * master_evaluations.len());
         master_evaluations_bytes.write_many(master_evaluations);
@@ -69,16 +195,134 @@ where
             worker_evaluation_bytes
         }).collect();
 
+        // Convert the blinding evaluations into a vector of bytes; this is empty on the non-ZK
+        // path, keeping the common case's wire format a single one-byte LEB128 length prefix larger.
+        let mut blinding_evaluations_bytes = Vec::with_capacity(E::ELEMENT_BYTES * blinding_evaluations.len());
+        blinding_evaluations_bytes.write_many(blinding_evaluations);
+
         FoldAndBatchProof {
             folding_proofs,
             fri_proof,
             worker_evaluations,
             master_evaluations: master_evaluations_bytes,
             worker_layer_commitments,
-            master_layer_commitments,    
+            master_layer_commitments,
+            function_commitments,
+            function_openings,
+            blinding_evaluations: blinding_evaluations_bytes,
+            master_remainder_points: Vec::new(),
+            master_remainder_evaluations: Vec::new(),
+            worker_evaluation_parity: Vec::new(),
+            worker_evaluation_parity_points: Vec::new(),
+            worker_remainder_coefficients: Vec::new(),
+            pow_nonce,
         }
     }
 
+    /// Attaches the master's FRI remainder as evaluations over a small canonical point set,
+    /// alongside the coefficients already embedded in [fri_proof](Self::fri_proof), so the
+    /// verifier can cross-check the remainder via Lagrange interpolation (see
+    /// [lagrange_interpolate_eval](crate::fold_and_batch_verifier::lagrange_interpolate_eval))
+    /// rather than trusting the transmitted coefficients alone.
+    ///
+    /// `points` and `evaluations` must be the same length. This is called after the proof has
+    /// already been built, since the remainder only takes its final shape once the master's FRI
+    /// query phase has run.
+    pub(crate) fn with_master_remainder<E: FieldElement>(mut self, points: Vec<E>, evaluations: Vec<E>) -> Self {
+        assert_eq!(points.len(), evaluations.len(), "remainder points and evaluations must be the same length");
+
+        let mut points_bytes = Vec::with_capacity(E::ELEMENT_BYTES * points.len());
+        points_bytes.write_many(points);
+        self.master_remainder_points = points_bytes;
+
+        let mut evaluations_bytes = Vec::with_capacity(E::ELEMENT_BYTES * evaluations.len());
+        evaluations_bytes.write_many(evaluations);
+        self.master_remainder_evaluations = evaluations_bytes;
+
+        self
+    }
+
+    /// Attaches `parity_evaluations`, the Reed-Solomon parity vectors computed by
+    /// [encode_worker_evaluation_parity](crate::fold_and_batch_prover::encode_worker_evaluation_parity)
+    /// over [worker_evaluations](Self::worker_evaluations), alongside the canonical points they
+    /// were evaluated at, so that a verifier missing up to `parity_evaluations.len()` worker
+    /// evaluation vectors can rebuild them instead of rejecting the proof outright.
+    ///
+    /// `parity_evaluations` and `parity_points` must be the same length. This is called after the
+    /// proof has already been built, since the parity vectors are derived from every worker's
+    /// evaluations at once.
+    pub(crate) fn with_worker_evaluation_parity<E: FieldElement>(
+        mut self,
+        parity_evaluations: Vec<Vec<E>>,
+        parity_points: Vec<E>,
+    ) -> Self {
+        assert_eq!(
+            parity_evaluations.len(),
+            parity_points.len(),
+            "parity evaluations and parity points must be the same length"
+        );
+
+        self.worker_evaluation_parity = parity_evaluations
+            .iter()
+            .map(|eval_vector| {
+                let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES * eval_vector.len());
+                bytes.write_many(eval_vector);
+                bytes
+            })
+            .collect();
+
+        let mut points_bytes = Vec::with_capacity(E::ELEMENT_BYTES * parity_points.len());
+        points_bytes.write_many(parity_points);
+        self.worker_evaluation_parity_points = points_bytes;
+
+        self
+    }
+
+    /// Attaches `coefficients`, the [interpolate_last_layer](crate::FoldingProver::interpolate_last_layer)
+    /// output for every worker that was configured with
+    /// [FoldingOptions::with_interpolated_remainder](crate::FoldingOptions::with_interpolated_remainder)
+    /// (see [fold_and_batch_worker_commit](crate::fold_and_batch_prover::fold_and_batch_worker_commit)),
+    /// so the verifier can check that worker's last layer directly via
+    /// [evaluate_poly_horner](crate::fold_and_batch_verifier::evaluate_poly_horner) instead of
+    /// opening a Merkle proof against its function commitment. A worker that was not configured
+    /// with `interpolate_remainder` contributes an empty entry here, which the verifier reads as
+    /// "verify this worker the usual way".
+    ///
+    /// `coefficients` must have exactly one entry per worker, i.e. the same length as
+    /// [folding_proofs](Self::folding_proofs).
+    ///
+    /// # Panics
+    /// Panics if `coefficients.len()` does not equal the number of workers in this proof.
+    pub(crate) fn with_worker_remainder_coefficients<E: FieldElement>(mut self, coefficients: Vec<Vec<E>>) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            self.folding_proofs.len(),
+            "worker remainder coefficients must have exactly one entry per worker"
+        );
+
+        self.worker_remainder_coefficients = coefficients
+            .iter()
+            .map(|coefficient_vec| {
+                let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES * coefficient_vec.len());
+                bytes.write_many(coefficient_vec);
+                bytes
+            })
+            .collect();
+
+        self
+    }
+
+    /// Simulates worker `worker_index`'s evaluations going missing in transit by replacing its
+    /// entry in [worker_evaluations](Self::worker_evaluations) with the wire-format sentinel for
+    /// "absent" (an empty byte vector), so tests can exercise the erasure-coding recovery path in
+    /// [FoldAndBatchVerifier::verify_fold_and_batch](crate::fold_and_batch_verifier::FoldAndBatchVerifier::verify_fold_and_batch)
+    /// without a real dropped worker.
+    #[cfg(test)]
+    pub(crate) fn with_missing_worker_evaluation(mut self, worker_index: usize) -> Self {
+        self.worker_evaluations[worker_index] = Vec::new();
+        self
+    }
+
     pub(crate) fn folding_proofs(&self) -> &Vec<FoldingProof> {
         &self.folding_proofs
     }
@@ -91,11 +335,99 @@ where
         &self.master_layer_commitments
     }
 
+    /// Returns the nonce found by the master prover's proof-of-work grinding search, to be
+    /// used by the verifier to reseed the public coin before query positions are drawn.
+    pub(crate) fn pow_nonce(&self) -> u64 {
+        self.pow_nonce
+    }
+
 
     pub(crate) fn worker_layer_commitments(&self) -> &Vec<Vec<H::Digest>> {
         &self.worker_layer_commitments
     }
 
+    /// Returns the per-worker commitments to each worker's own last-layer evaluations, in the
+    /// same order as [worker_layer_commitments](Self::worker_layer_commitments).
+    pub(crate) fn function_commitments(&self) -> &Vec<H::Digest> {
+        &self.function_commitments
+    }
+
+    /// Returns the per-worker opening proofs authenticating each worker's last-layer evaluations
+    /// at the queried positions against the matching entry of
+    /// [function_commitments](Self::function_commitments).
+    pub(crate) fn function_openings(&self) -> &Vec<FriProofLayer> {
+        &self.function_openings
+    }
+
+    /// Returns the number of elements in [blinding_evaluations](Self::parse_blinding_evaluations).
+    ///
+    /// This is 0 when the proof was built without zero-knowledge hiding enabled.
+    pub fn num_blinding_evaluations<E: FieldElement>(&self) -> usize {
+        self.blinding_evaluations.len() / E::ELEMENT_BYTES
+    }
+
+    /// Returns the combined blinding contribution the master added to every worker's last layer
+    /// before batching, parsed from the bytes stored in this proof.
+    ///
+    /// Returns an empty vector when the proof was built without zero-knowledge hiding enabled.
+    ///
+    /// # Errors
+    /// Returns an error if the blinding evaluations could not be parsed correctly, or if not all
+    /// bytes have been consumed while parsing them.
+    pub fn parse_blinding_evaluations<E: FieldElement>(&self) -> Result<Vec<E>, VerifierError> {
+        let num_elements = self.num_blinding_evaluations::<E>();
+
+        let mut reader = SliceReader::new(&self.blinding_evaluations);
+        let blinding_evaluations = reader.read_many(num_elements).map_err(|err| {
+            VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+        })?;
+        if reader.has_more_bytes() {
+            return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+        }
+        Ok(blinding_evaluations)
+    }
+
+
+    /// Returns the number of points in [parse_master_remainder](Self::parse_master_remainder).
+    ///
+    /// This is 0 when the proof was built without the remainder's evaluation-form side channel
+    /// (see [with_master_remainder](Self::with_master_remainder)).
+    pub fn num_master_remainder_points<E: FieldElement>(&self) -> usize {
+        self.master_remainder_points.len() / E::ELEMENT_BYTES
+    }
+
+    /// Returns the canonical point set and the master's claimed remainder evaluations there,
+    /// parsed from the bytes stored in this proof.
+    ///
+    /// Returns a pair of empty vectors when the proof was built without the remainder's
+    /// evaluation-form side channel.
+    ///
+    /// # Errors
+    /// Returns an error if either vector could not be parsed correctly, or if not all bytes have
+    /// been consumed while parsing them.
+    pub fn parse_master_remainder<E: FieldElement>(&self) -> Result<(Vec<E>, Vec<E>), VerifierError> {
+        let num_points = self.num_master_remainder_points::<E>();
+
+        let mut points_reader = SliceReader::new(&self.master_remainder_points);
+        let points = points_reader.read_many(num_points).map_err(|err| {
+            VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+        })?;
+        if points_reader.has_more_bytes() {
+            return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+        }
+
+        let num_evaluations = self.master_remainder_evaluations.len() / E::ELEMENT_BYTES;
+        let mut evaluations_reader = SliceReader::new(&self.master_remainder_evaluations);
+        let evaluations = evaluations_reader.read_many(num_evaluations).map_err(|err| {
+            VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+        })?;
+        if evaluations_reader.has_more_bytes() {
+            return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+        }
+
+        Ok((points, evaluations))
+    }
+
 
     /// Returns the number of the evaluation values in this proof.
     ///
@@ -108,33 +440,30 @@ where
     // Returns the number of bytes in this proof.
     pub fn size(&self) -> usize {
 
-        // +4 for the length of the folding_proofs vector
-        let folding_proofs_size = self.folding_proofs.iter().fold(4, |acc, folding_proof| acc + folding_proof.size());
-    
+        // + the LEB128-encoded length of the folding_proofs vector
+        let folding_proofs_size = self.folding_proofs.iter().fold(leb128_size(self.folding_proofs.len()), |acc, folding_proof| acc + folding_proof.size());
+
         let fri_proof_size = self.fri_proof.size();
 
-        // +4 for the length of the worker_evaluations vector.
-        // +2 for the length of each vector in worker_evaluations.
-        let worker_evaluations_size = self.worker_evaluations.iter().fold(4, |acc, byte_vec| acc + byte_vec.len() + 2);
+        // + the LEB128-encoded length of the worker_evaluations vector.
+        // + the LEB128-encoded length of each vector in worker_evaluations.
+        let worker_evaluations_size = self.worker_evaluations.iter().fold(leb128_size(self.worker_evaluations.len()), |acc, byte_vec| acc + byte_vec.len() + leb128_size(byte_vec.len()));
 
-        // +2 for the length of the master_evaluations vector.
-        let master_evaluations_size = self.master_evaluations.len() + 2;
+        // + the LEB128-encoded length of the master_evaluations vector.
+        let master_evaluations_size = self.master_evaluations.len() + leb128_size(self.master_evaluations.len());
 
-        // +4 for the length of worker_layer_commitments
-        // +2 for the length of each vector in worker_layer_commitments
-        let worker_layer_commitments_size = self.worker_layer_commitments.iter().fold(4, |acc, commitment_vec| {
-            if commitment_vec.len() == 0 {
-                panic!("The length of a worker layer commitments vector is 0");
+        // + the LEB128-encoded length of worker_layer_commitments
+        // + the LEB128-encoded length of each vector in worker_layer_commitments (which may be
+        // empty, since a worker's last layer is no longer committed individually)
+        let worker_layer_commitments_size = self.worker_layer_commitments.iter().fold(leb128_size(self.worker_layer_commitments.len()), |acc, commitment_vec| {
+            match commitment_vec.first() {
+                Some(commitment) => acc + commitment.get_size_hint() * commitment_vec.len() + leb128_size(commitment_vec.len()),
+                None => acc + leb128_size(0),
             }
-            let commitment_size = commitment_vec[0].get_size_hint();
-            if commitment_size == 0 {
-                panic!("The size of a worker layer commitment is 0");
-            }
-            acc + commitment_size * commitment_vec.len() + 2
             }
         );
 
-        // +2 for the length of master_layer_commitments
+        // + the LEB128-encoded length of master_layer_commitments
         if self.master_layer_commitments().len() == 0 {
             panic!("The length of master layer commitments vector is 0");
         }
@@ -142,14 +471,49 @@ where
         if commitment_size == 0 {
             panic!("The size of a master layer commitment is 0");
         }
-        let master_layer_commitments_size = self.master_layer_commitments().len() * commitment_size + 2;
+        let master_layer_commitments_size = self.master_layer_commitments().len() * commitment_size + leb128_size(self.master_layer_commitments().len());
+
+        // + the LEB128-encoded length of function_commitments (one per worker)
+        // + the LEB128-encoded length of function_openings (always the same length as
+        // function_commitments, so no separate length prefix is needed for it)
+        let function_commitments_size = self.function_commitments.iter().fold(leb128_size(self.function_commitments.len()), |acc, commitment| acc + commitment.get_size_hint());
+        let function_openings_size = self.function_openings.iter().fold(0, |acc, opening| acc + opening.size());
 
-        folding_proofs_size + 
-        fri_proof_size + 
+        // + the LEB128-encoded length of blinding_evaluations (empty on the non-ZK path)
+        let blinding_evaluations_size = self.blinding_evaluations.len() + leb128_size(self.blinding_evaluations.len());
+
+        // + the LEB128-encoded lengths of master_remainder_points and master_remainder_evaluations
+        // (both empty when the proof was built without the remainder's evaluation-form side
+        // channel)
+        let master_remainder_size = self.master_remainder_points.len() + leb128_size(self.master_remainder_points.len())
+            + self.master_remainder_evaluations.len() + leb128_size(self.master_remainder_evaluations.len());
+
+        // + the LEB128-encoded length of worker_evaluation_parity.
+        // + the LEB128-encoded length of each vector in worker_evaluation_parity.
+        // + the LEB128-encoded length of worker_evaluation_parity_points.
+        // (all empty when the proof was built without worker evaluation erasure coding enabled)
+        let worker_evaluation_parity_size = self.worker_evaluation_parity.iter().fold(leb128_size(self.worker_evaluation_parity.len()), |acc, byte_vec| acc + byte_vec.len() + leb128_size(byte_vec.len()))
+            + self.worker_evaluation_parity_points.len() + leb128_size(self.worker_evaluation_parity_points.len());
+
+        // + the LEB128-encoded length of worker_remainder_coefficients
+        // + the LEB128-encoded length of each vector in worker_remainder_coefficients
+        // (empty when the proof was built without remainder interpolation enabled for any worker)
+        let worker_remainder_coefficients_size = self.worker_remainder_coefficients.iter().fold(leb128_size(self.worker_remainder_coefficients.len()), |acc, byte_vec| acc + byte_vec.len() + leb128_size(byte_vec.len()));
+
+        // +8 for the pow_nonce
+        folding_proofs_size +
+        fri_proof_size +
         worker_evaluations_size +
         master_evaluations_size +
-        worker_layer_commitments_size + 
-        master_layer_commitments_size
+        worker_layer_commitments_size +
+        master_layer_commitments_size +
+        function_commitments_size +
+        function_openings_size +
+        blinding_evaluations_size +
+        master_remainder_size +
+        worker_evaluation_parity_size +
+        worker_remainder_coefficients_size +
+        8
     }
 
 
@@ -192,6 +556,82 @@ where
         }
         Ok(worker_evaluations)
     }
+
+    /// Returns the number of Reed-Solomon parity vectors attached via
+    /// [with_worker_evaluation_parity](Self::with_worker_evaluation_parity).
+    ///
+    /// This is 0 when the proof was built without worker evaluation erasure coding enabled.
+    pub fn num_worker_evaluation_parity_vectors(&self) -> usize {
+        self.worker_evaluation_parity.len()
+    }
+
+    /// Returns the parity vectors and the canonical points they were evaluated at, parsed from
+    /// the bytes stored in this proof.
+    ///
+    /// Returns a pair of empty vectors when the proof was built without worker evaluation erasure
+    /// coding enabled.
+    ///
+    /// # Errors
+    /// Returns an error if either could not be parsed correctly, or if not all bytes have been
+    /// consumed while parsing them.
+    pub fn parse_worker_evaluation_parity<E: FieldElement>(&self) -> Result<(Vec<Vec<E>>, Vec<E>), VerifierError> {
+        let mut parity_evaluations = Vec::with_capacity(self.worker_evaluation_parity.len());
+        for byte_vec in self.worker_evaluation_parity.iter() {
+            let mut reader = SliceReader::new(byte_vec);
+            let num_elements = byte_vec.len() / E::ELEMENT_BYTES;
+            let eval_vec: Vec<E> = reader.read_many(num_elements).map_err(|err| {
+                VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+            })?;
+            if reader.has_more_bytes() {
+                return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+            }
+            parity_evaluations.push(eval_vec);
+        }
+
+        let num_points = self.worker_evaluation_parity_points.len() / E::ELEMENT_BYTES;
+        let mut points_reader = SliceReader::new(&self.worker_evaluation_parity_points);
+        let parity_points = points_reader.read_many(num_points).map_err(|err| {
+            VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+        })?;
+        if points_reader.has_more_bytes() {
+            return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+        }
+
+        Ok((parity_evaluations, parity_points))
+    }
+
+    /// Returns, for each worker, the coefficients attached via
+    /// [with_worker_remainder_coefficients](Self::with_worker_remainder_coefficients), parsed from
+    /// the bytes stored in this proof.
+    ///
+    /// Returns one entry per worker, in the same order as
+    /// [worker_layer_commitments](Self::worker_layer_commitments); a worker that was not
+    /// configured with `interpolate_remainder` has an empty entry, which the verifier reads as
+    /// "verify this worker the usual way". Returns a vector of empty entries, one per worker, when
+    /// this side channel was never attached at all.
+    ///
+    /// # Errors
+    /// Returns an error if any entry could not be parsed correctly, or if not all bytes have been
+    /// consumed while parsing it.
+    pub fn parse_worker_remainder_coefficients<E: FieldElement>(&self) -> Result<Vec<Vec<E>>, VerifierError> {
+        if self.worker_remainder_coefficients.is_empty() {
+            return Ok(vec![Vec::new(); self.folding_proofs.len()]);
+        }
+
+        let mut coefficients = Vec::with_capacity(self.worker_remainder_coefficients.len());
+        for byte_vec in self.worker_remainder_coefficients.iter() {
+            let mut reader = SliceReader::new(byte_vec);
+            let num_elements = byte_vec.len() / E::ELEMENT_BYTES;
+            let coefficient_vec: Vec<E> = reader.read_many(num_elements).map_err(|err| {
+                VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+            })?;
+            if reader.has_more_bytes() {
+                return Err(VerifierError::UnconsumedBytesInEvaluationsVector);
+            }
+            coefficients.push(coefficient_vec);
+        }
+        Ok(coefficients)
+    }
 }
 
 // SERIALIZATION / DESERIALIZATION
@@ -201,38 +641,54 @@ impl Serializable for FoldingProof {
     /// Serializes this folding proof and writes the resulting bytes to the specified `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         // write the number of layers into the target
-        target.write_u8(self.folding_proof.len() as u8);
+        write_leb128(target, self.folding_proof.len());
 
         // write each layer into the target
         for layer in self.folding_proof.iter() {
             layer.write_into(target);
         }
+
+        // write the folding arity used at each layer
+        for &arity in self.folding_schedule.iter() {
+            target.write_u8(arity);
+        }
+
+        target.write_u64(self.pow_nonce);
     }
 }
 
 impl Deserializable for FoldingProof {
     /// Reads a folding proof from the `source` and returns it.
     ///
+    /// `folding_proof` may be empty, since a worker's last layer is no longer committed (or
+    /// opened) individually.
+    ///
     /// # Errors
-    /// Returns an error if a valid [FriProofLayer] could not be read from the specified source.
+    /// Returns an error if a valid [FriProofLayer] could not be read from the specified source, or
+    /// if the LEB128-encoded layer count is malformed.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
 
         // read the number of layers in this FoldingProof
-        let num_layers = source.read_u8()?;
-        if num_layers == 0 {
-            return Err(DeserializationError::InvalidValue(
-                "a FoldingProof must contain at least one FriProofLayer".to_string(),
-            ));
-        }
+        let num_layers = read_leb128(source)?;
 
-        // read the layers
-        let mut folding_proof = Vec::with_capacity(num_layers.into());
+        // read the layers; the claimed `num_layers` is untrusted, so this does not pre-allocate
+        // based on it -- each iteration fails fast with a `DeserializationError` the moment
+        // `source` runs out of bytes, instead of the claimed count driving a large allocation.
+        let mut folding_proof = Vec::new();
         for _ in 0..num_layers {
             let layer = FriProofLayer::read_from(source)?;
             folding_proof.push(layer);
         }
 
-        Ok(FoldingProof { folding_proof })
+        // read the folding arity used at each layer
+        let mut folding_schedule = Vec::new();
+        for _ in 0..num_layers {
+            folding_schedule.push(source.read_u8()?);
+        }
+
+        let pow_nonce = source.read_u64()?;
+
+        Ok(FoldingProof { folding_proof, folding_schedule, pow_nonce })
     }
 }
 
@@ -244,7 +700,7 @@ where
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
 
         // write folding proofs
-        target.write_u32(self.folding_proofs.len() as u32);
+        write_leb128(target, self.folding_proofs.len());
         for folding_proof in self.folding_proofs.iter() {
             folding_proof.write_into(target);
         }
@@ -253,30 +709,72 @@ where
         self.fri_proof.write_into(target);
 
         // write worker evaluations
-        target.write_u32(self.worker_evaluations.len() as u32);
+        write_leb128(target, self.worker_evaluations.len());
         for eval_vec in self.worker_evaluations.iter() {
-            target.write_u16(eval_vec.len() as u16);
+            write_leb128(target, eval_vec.len());
             target.write_bytes(&eval_vec);
         }
 
         // write master evaluations
-        target.write_u16(self.master_evaluations.len() as u16);
+        write_leb128(target, self.master_evaluations.len());
         target.write_bytes(&self.master_evaluations);
 
         // write worker layer commitments
-        target.write_u32(self.worker_layer_commitments.len() as u32);
+        write_leb128(target, self.worker_layer_commitments.len());
         for layer_commitments in self.worker_layer_commitments.iter() {
-            target.write_u8(layer_commitments.len() as u8);
+            write_leb128(target, layer_commitments.len());
             for commitment in layer_commitments.iter() {
                 commitment.write_into(target);
             }
         }
 
         // write master layer commitments
-        target.write_u8(self.master_layer_commitments.len() as u8);
+        write_leb128(target, self.master_layer_commitments.len());
         for commitment in self.master_layer_commitments.iter() {
             commitment.write_into(target);
         }
+
+        // write the per-worker function commitments and openings (function_openings always has
+        // the same length as function_commitments, so it reuses that length prefix)
+        write_leb128(target, self.function_commitments.len());
+        for commitment in self.function_commitments.iter() {
+            commitment.write_into(target);
+        }
+        for opening in self.function_openings.iter() {
+            opening.write_into(target);
+        }
+
+        // write the combined blinding evaluations (empty on the non-ZK path)
+        write_leb128(target, self.blinding_evaluations.len());
+        target.write_bytes(&self.blinding_evaluations);
+
+        // write the master remainder's evaluation-form side channel (both empty unless attached
+        // via `with_master_remainder`)
+        write_leb128(target, self.master_remainder_points.len());
+        target.write_bytes(&self.master_remainder_points);
+        write_leb128(target, self.master_remainder_evaluations.len());
+        target.write_bytes(&self.master_remainder_evaluations);
+
+        // write the worker evaluation parity vectors and the canonical points they were
+        // evaluated at (both empty unless attached via `with_worker_evaluation_parity`)
+        write_leb128(target, self.worker_evaluation_parity.len());
+        for eval_vec in self.worker_evaluation_parity.iter() {
+            write_leb128(target, eval_vec.len());
+            target.write_bytes(eval_vec);
+        }
+        write_leb128(target, self.worker_evaluation_parity_points.len());
+        target.write_bytes(&self.worker_evaluation_parity_points);
+
+        // write the per-worker interpolated remainder coefficients (empty unless attached via
+        // `with_worker_remainder_coefficients`)
+        write_leb128(target, self.worker_remainder_coefficients.len());
+        for coefficient_vec in self.worker_remainder_coefficients.iter() {
+            write_leb128(target, coefficient_vec.len());
+            target.write_bytes(coefficient_vec);
+        }
+
+        // write the proof-of-work nonce
+        target.write_u64(self.pow_nonce);
     }
 }
 
@@ -290,50 +788,281 @@ where
     /// Returns an error if a valid proof could not be read from the source.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
 
-        // read folding proofs
-        let num_layers = source.read_u32()? as usize;
-        let folding_proofs = source.read_many(num_layers)?;
-        
+        // read folding proofs; every length below is LEB128-encoded and untrusted, so none of
+        // these loops pre-allocate based on the claimed count -- each iteration fails fast with a
+        // `DeserializationError` the moment `source` runs out of bytes, instead of the claimed
+        // count driving a large allocation.
+        let num_folding_proofs = read_leb128(source)?;
+        let mut folding_proofs = Vec::new();
+        for _ in 0..num_folding_proofs {
+            folding_proofs.push(FoldingProof::read_from(source)?);
+        }
+
         // read FRI proof
         let fri_proof = FriProof::read_from(source)?;
 
         // read worker evaluations
-        let num_workers = source.read_u32()? as usize;
-        let mut worker_evaluations = Vec::with_capacity(num_workers);
+        let num_workers = read_leb128(source)?;
+        let mut worker_evaluations = Vec::new();
         for _ in 0..num_workers {
-            let num_evaluations_bytes = source.read_u16()? as usize;
+            let num_evaluations_bytes = read_leb128(source)?;
             let eval_vec = source.read_vec(num_evaluations_bytes)?;
             worker_evaluations.push(eval_vec);
         }
 
         // read master evaluations
-        let num_evaluations_bytes = source.read_u16()? as usize;
+        let num_evaluations_bytes = read_leb128(source)?;
         let master_evaluations = source.read_vec(num_evaluations_bytes)?;
 
 
         // read worker layer commitments
-        let num_workers = source.read_u32()? as usize;
-        let mut worker_layer_commitments = Vec::with_capacity(num_workers);
+        let num_workers = read_leb128(source)?;
+        let mut worker_layer_commitments = Vec::new();
         for _ in 0..num_workers {
-            let num_commitments = source.read_u8()? as usize;
-            let layer_commitments = source.read_many(num_commitments)?;
+            let num_commitments = read_leb128(source)?;
+            let mut layer_commitments = Vec::new();
+            for _ in 0..num_commitments {
+                layer_commitments.push(H::Digest::read_from(source)?);
+            }
             worker_layer_commitments.push(layer_commitments);
         }
 
         // read master layer commitments
-        let num_commitments = source.read_u8()? as usize;
-        let master_layer_commitments = source.read_many(num_commitments)?;
-        
+        let num_commitments = read_leb128(source)?;
+        let mut master_layer_commitments = Vec::new();
+        for _ in 0..num_commitments {
+            master_layer_commitments.push(H::Digest::read_from(source)?);
+        }
+
+        // read the per-worker function commitments and openings (function_openings always has
+        // the same length as function_commitments, so it reuses that length prefix)
+        let num_functions = read_leb128(source)?;
+        let mut function_commitments = Vec::new();
+        for _ in 0..num_functions {
+            function_commitments.push(H::Digest::read_from(source)?);
+        }
+        let mut function_openings = Vec::new();
+        for _ in 0..num_functions {
+            function_openings.push(FriProofLayer::read_from(source)?);
+        }
+
+        // read the combined blinding evaluations (empty on the non-ZK path)
+        let num_blinding_evaluations_bytes = read_leb128(source)?;
+        let blinding_evaluations = source.read_vec(num_blinding_evaluations_bytes)?;
+
+        // read the master remainder's evaluation-form side channel (both empty unless attached
+        // via `with_master_remainder`)
+        let num_master_remainder_points_bytes = read_leb128(source)?;
+        let master_remainder_points = source.read_vec(num_master_remainder_points_bytes)?;
+        let num_master_remainder_evaluations_bytes = read_leb128(source)?;
+        let master_remainder_evaluations = source.read_vec(num_master_remainder_evaluations_bytes)?;
+
+        // read the worker evaluation parity vectors and the canonical points they were evaluated
+        // at (both empty unless attached via `with_worker_evaluation_parity`)
+        let num_parity_vectors = read_leb128(source)?;
+        let mut worker_evaluation_parity = Vec::new();
+        for _ in 0..num_parity_vectors {
+            let num_evaluation_bytes = read_leb128(source)?;
+            let eval_vec = source.read_vec(num_evaluation_bytes)?;
+            worker_evaluation_parity.push(eval_vec);
+        }
+        let num_parity_points_bytes = read_leb128(source)?;
+        let worker_evaluation_parity_points = source.read_vec(num_parity_points_bytes)?;
+
+        // read the per-worker interpolated remainder coefficients (empty unless attached via
+        // `with_worker_remainder_coefficients`)
+        let num_remainder_coefficient_vectors = read_leb128(source)?;
+        let mut worker_remainder_coefficients = Vec::new();
+        for _ in 0..num_remainder_coefficient_vectors {
+            let num_coefficient_bytes = read_leb128(source)?;
+            let coefficient_vec = source.read_vec(num_coefficient_bytes)?;
+            worker_remainder_coefficients.push(coefficient_vec);
+        }
 
-        Ok(FoldAndBatchProof { 
+        // read the proof-of-work nonce
+        let pow_nonce = source.read_u64()?;
+
+        Ok(FoldAndBatchProof {
             folding_proofs,
-            fri_proof, 
+            fri_proof,
             worker_evaluations,
             master_evaluations,
             worker_layer_commitments,
-            master_layer_commitments
+            master_layer_commitments,
+            function_commitments,
+            function_openings,
+            blinding_evaluations,
+            master_remainder_points,
+            master_remainder_evaluations,
+            worker_evaluation_parity,
+            worker_evaluation_parity_points,
+            worker_remainder_coefficients,
+            pow_nonce,
          })
 
     }
 }
 
+// WORKER OUTPUT ENVELOPE
+// ================================================================================================
+//
+// A single Fold-and-Batch worker node produces four pieces of data that the master prover needs
+// in order to continue the protocol: the worker's final (innermost) layer of evaluations, the
+// commitments to every layer the worker built, the folding proof for the queried positions, and
+// the worker's evaluations at those same positions. Previously these were written to (and read
+// from) stdin as a sequence of fixed-size `[u8; 32]` buffers, with the master hard-coding the
+// number of worker layers and the field element's width; this made the two binaries brittle to
+// change independently of one another. [FoldAndBatchWorkerOutput] bundles the four pieces behind
+// a single length-prefixed, versioned, field/hasher-tagged envelope so that a worker and a master
+// can be mismatched (wrong field, wrong hasher, wrong wire version) without silently
+// misinterpreting each other's bytes.
+
+const WORKER_OUTPUT_MAGIC: u32 = 0x4641_5742; // ASCII "FAWB" ("Fold-And-batch Worker output")
+const WORKER_OUTPUT_VERSION: u8 = 1;
+
+/// The data a single worker node sends to the master prover in the Fold-and-Batch protocol.
+pub struct FoldAndBatchWorkerOutput<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher,
+{
+    batched_fri_input: Vec<E>,
+    layer_commitments: Vec<H::Digest>,
+    folding_proof: FoldingProof,
+    queried_evaluations: Vec<E>,
+}
+
+impl<E, H> FoldAndBatchWorkerOutput<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher,
+{
+    pub fn new(
+        batched_fri_input: Vec<E>,
+        layer_commitments: Vec<H::Digest>,
+        folding_proof: FoldingProof,
+        queried_evaluations: Vec<E>,
+    ) -> Self {
+        FoldAndBatchWorkerOutput {
+            batched_fri_input,
+            layer_commitments,
+            folding_proof,
+            queried_evaluations,
+        }
+    }
+
+    /// Consumes this envelope and returns its four constituent pieces, in the order the master
+    /// prover's commit and query phases expect them.
+    pub fn into_parts(self) -> (Vec<E>, Vec<H::Digest>, FoldingProof, Vec<E>) {
+        (self.batched_fri_input, self.layer_commitments, self.folding_proof, self.queried_evaluations)
+    }
+}
+
+impl<E, H> Serializable for FoldAndBatchWorkerOutput<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher,
+{
+    /// Serializes this worker output and writes the resulting bytes to the specified `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // write the envelope header: magic bytes, wire version, and the field/hasher this
+        // envelope was produced with, so that a reader instantiated with the wrong type
+        // parameters fails fast instead of misparsing the bytes that follow.
+        target.write_u32(WORKER_OUTPUT_MAGIC);
+        target.write_u8(WORKER_OUTPUT_VERSION);
+        write_type_tag::<E, _>(target);
+        write_type_tag::<H, _>(target);
+
+        // write the worker's final layer of evaluations (the input to the master's batching step)
+        target.write_u32(self.batched_fri_input.len() as u32);
+        for value in self.batched_fri_input.iter() {
+            value.write_into(target);
+        }
+
+        // write the worker's layer commitments
+        target.write_u8(self.layer_commitments.len() as u8);
+        for commitment in self.layer_commitments.iter() {
+            commitment.write_into(target);
+        }
+
+        // write the folding proof
+        self.folding_proof.write_into(target);
+
+        // write the worker's evaluations at the queried positions
+        target.write_u32(self.queried_evaluations.len() as u32);
+        for value in self.queried_evaluations.iter() {
+            value.write_into(target);
+        }
+    }
+}
+
+impl<E, H> Deserializable for FoldAndBatchWorkerOutput<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher,
+{
+    /// Reads a worker output envelope from the `source` and returns it.
+    ///
+    /// # Errors
+    /// Returns an error if the envelope's magic bytes, wire version, field tag, or hasher tag do
+    /// not match what this reader expects, or if a valid envelope could not otherwise be parsed.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let magic = source.read_u32()?;
+        if magic != WORKER_OUTPUT_MAGIC {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid worker output envelope: expected magic bytes {WORKER_OUTPUT_MAGIC:#x}, found {magic:#x}"
+            )));
+        }
+
+        let version = source.read_u8()?;
+        if version != WORKER_OUTPUT_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported worker output envelope version: expected {WORKER_OUTPUT_VERSION}, found {version}"
+            )));
+        }
+
+        read_type_tag::<E, _>(source)?;
+        read_type_tag::<H, _>(source)?;
+
+        let num_inputs = source.read_u32()? as usize;
+        let batched_fri_input = source.read_many(num_inputs)?;
+
+        let num_commitments = source.read_u8()? as usize;
+        let layer_commitments = source.read_many(num_commitments)?;
+
+        let folding_proof = FoldingProof::read_from(source)?;
+
+        let num_evaluations = source.read_u32()? as usize;
+        let queried_evaluations = source.read_many(num_evaluations)?;
+
+        Ok(FoldAndBatchWorkerOutput {
+            batched_fri_input,
+            layer_commitments,
+            folding_proof,
+            queried_evaluations,
+        })
+    }
+}
+
+/// Writes a length-prefixed tag identifying type `T`, so that [FoldAndBatchWorkerOutput::read_from]
+/// can reject an envelope produced for a different field element or hasher.
+fn write_type_tag<T, W: ByteWriter>(target: &mut W) {
+    let name = type_name::<T>();
+    target.write_u16(name.len() as u16);
+    target.write_bytes(name.as_bytes());
+}
+
+/// Reads back a tag written by [write_type_tag] and checks it against the expected type `T`.
+fn read_type_tag<T, R: ByteReader>(source: &mut R) -> Result<(), DeserializationError> {
+    let expected = type_name::<T>();
+    let len = source.read_u16()? as usize;
+    let found = source.read_vec(len)?;
+    if found != expected.as_bytes() {
+        return Err(DeserializationError::InvalidValue(format!(
+            "worker output envelope type mismatch: expected `{expected}`, found `{}`",
+            alloc::string::String::from_utf8_lossy(&found)
+        )));
+    }
+    Ok(())
+}
+
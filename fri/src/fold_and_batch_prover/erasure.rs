@@ -0,0 +1,88 @@
+use alloc::{vec, vec::Vec};
+use math::FieldElement;
+
+use crate::fold_and_batch_verifier::lagrange_interpolate_eval;
+
+// WORKER EVALUATION ERASURE CODING (ENCODE)
+// ================================================================================================
+//
+// `FoldAndBatchProof::worker_evaluations` stores every worker's evaluations at the queried
+// positions verbatim, so a single worker payload going missing makes the whole proof
+// unreconstructable by the master. [encode_worker_evaluation_parity] adds a systematic
+// Reed-Solomon dispersal on top: the `n` worker vectors, read position-by-position, are treated
+// as a message of `n` field symbols and encoded to `n + parity_count` symbols by assigning each
+// worker vector the canonical point `0, 1, ..., n - 1` (in worker order) and evaluating the
+// resulting degree `< n` interpolant at `parity_count` further points. A verifier missing up to
+// `parity_count` worker vectors can then rebuild them from the survivors; see
+// [reconstruct_worker_evaluations](crate::fold_and_batch_verifier::reconstruct_worker_evaluations).
+
+/// Returns the `count` canonical points `start, start + 1, ..., start + count - 1`, used to
+/// identify worker vectors (`start == 0`) and parity vectors (`start == n`) in the erasure-coded
+/// set.
+pub(crate) fn canonical_points<E: FieldElement>(start: usize, count: usize) -> Vec<E> {
+    let first = (0..start).fold(E::ZERO, |acc, _| acc + E::ONE);
+    core::iter::successors(Some(first), |&p| Some(p + E::ONE)).take(count).collect()
+}
+
+/// Returns `parity_count` parity vectors, each the same length as every vector in
+/// `worker_evaluations`, alongside the canonical points they were evaluated at.
+///
+/// # Panics
+/// Panics if `worker_evaluations` is empty, or if its vectors do not all have the same length.
+pub(crate) fn encode_worker_evaluation_parity<E: FieldElement>(
+    worker_evaluations: &[Vec<E>],
+    parity_count: usize,
+) -> (Vec<Vec<E>>, Vec<E>) {
+    let n = worker_evaluations.len();
+    assert!(n > 0, "erasure coding needs at least one worker evaluation vector");
+
+    let position_count = worker_evaluations[0].len();
+    assert!(
+        worker_evaluations.iter().all(|w| w.len() == position_count),
+        "every worker evaluation vector must have the same length to be erasure-coded together"
+    );
+
+    let worker_points = canonical_points::<E>(0, n);
+    let parity_points = canonical_points::<E>(n, parity_count);
+
+    let mut parity_evaluations = vec![Vec::with_capacity(position_count); parity_count];
+    for j in 0..position_count {
+        let message: Vec<E> = worker_evaluations.iter().map(|w| w[j]).collect();
+        for (parity_vector, &point) in parity_evaluations.iter_mut().zip(parity_points.iter()) {
+            let value = lagrange_interpolate_eval(&worker_points, &message, point)
+                .expect("canonical points are constructed to be pairwise distinct");
+            parity_vector.push(value);
+        }
+    }
+
+    (parity_evaluations, parity_points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fold_and_batch_verifier::reconstruct_worker_evaluations;
+    use math::fields::f128::BaseElement;
+
+    #[test]
+    fn test_encode_then_reconstruct_recovers_dropped_worker() {
+        let worker_evaluations = vec![
+            vec![BaseElement::new(1), BaseElement::new(10)],
+            vec![BaseElement::new(2), BaseElement::new(20)],
+            vec![BaseElement::new(4), BaseElement::new(40)],
+        ];
+
+        let (parity_evaluations, parity_points) = encode_worker_evaluation_parity(&worker_evaluations, 1);
+        assert_eq!(parity_evaluations.len(), 1);
+
+        // Simulate worker 1 going missing.
+        let with_gap: Vec<Option<Vec<BaseElement>>> = worker_evaluations
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 1 { None } else { Some(w.clone()) })
+            .collect();
+
+        let reconstructed = reconstruct_worker_evaluations(&with_gap, &parity_evaluations, &parity_points).unwrap();
+        assert_eq!(reconstructed, worker_evaluations);
+    }
+}
@@ -1,8 +1,8 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
 
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use math::{fft, FieldElement, StarkField};
+use math::{FieldElement, StarkField};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 use utils::{
@@ -10,11 +10,14 @@ use utils::{
 };
 
 use crate::{
-    build_layer_commitment, fold_and_batch_proof::FoldingProof, folding::{apply_drp, fold_positions}, prover::query_layer, BatchedFriProver, DefaultProverChannel, FoldAndBatchProof, FriLayer, FriOptions, ProverChannel
+    batched_prover::{combine_poly_evaluations, random_blinding_evaluations}, build_layer_commitment, fold_and_batch_proof::FoldingProof, folding::{apply_drp, fold_positions}, prover::query_layer, transcript::RandomCoinTranscript, BatchedFriProver, DefaultProverChannel, FoldAndBatchProof, FriLayer, FriOptions, ProverChannel
 };
 
 mod options;
-pub use options::FoldingOptions;
+pub use options::{FoldingError, FoldingOptions, FoldingSecurityParams};
+
+mod erasure;
+pub(crate) use erasure::{canonical_points, encode_worker_evaluation_parity};
 
 
 #[cfg(test)]
@@ -22,6 +25,17 @@ mod tests;
 
 
 
+/// A low-degree test prover: [build_layers](Self::build_layers)/[build_proof](Self::build_proof)
+/// certify that a polynomial's evaluations are close to a low-degree codeword, the same
+/// guarantee [crate::FriProver] gives, but without committing or sending a remainder, since the
+/// Fold-and-Batch protocol hands this prover's last layer off to a master node to batch instead.
+///
+/// This type only proves low-degreeness; it has no notion of evaluating the committed polynomial
+/// at an arbitrary point outside the domain. [FoldingPcs](crate::folding_pcs::FoldingPcs) is the
+/// polynomial commitment scheme built on top of it that does: it wraps a [FoldingProver] with its
+/// own channel and a `commit`/`open` API, running this prover's fold/commit/query machinery on
+/// the quotient `(f(x) - f(z)) / (x - z)` to certify `f(z) = v`, the same way [crate::fri_pcs::FriPcs]
+/// is built on [crate::FriProver].
 pub struct FoldingProver<E, C, H, V>
 where
     E: FieldElement,
@@ -73,6 +87,11 @@ where
         self.options.domain_offset()
     }
 
+    /// Returns the [FoldingOptions] this prover was constructed with.
+    pub fn options(&self) -> &FoldingOptions {
+        &self.options
+    }
+
     /// Returns the number of FRI layers this prover should build. 
     fn num_fri_layers_to_build(&self) -> usize {
         self.options.num_fri_layers()
@@ -101,6 +120,15 @@ where
     /// the channel. After this the prover draws a random field element Î± from the channel, and
     /// uses it in the next application of the DRP.
     ///
+    /// Layers are necessarily built one after another, since each DRP is applied to the previous
+    /// layer's output; the per-layer `transpose_slice`/`apply_drp`/commitment work itself is
+    /// already parallelized across cosets and leaf ranges by `utils` and `crypto` under their own
+    /// `concurrent` feature, exactly as in [FriProver::build_layers](crate::FriProver::build_layers).
+    ///
+    /// The final layer's evaluations are returned uncommitted: rather than this worker committing
+    /// its own last layer, the master prover commits every worker's last layer, one function
+    /// commitment per worker, so committing it here would be redundant.
+    ///
     /// # Panics
     /// Panics if the prover state is dirty (the vector of layers is not empty).
     pub fn build_layers(&mut self, channel: &mut C, mut evaluations: Vec<E>) -> Vec<E> {
@@ -109,17 +137,9 @@ where
             "a prior proof generation request has not been completed yet"
         );
 
-        let mut last_eval_vector = Vec::new();
-
-        // reduce the degree by folding_factor at each iteration until the remaining polynomial
-        // has small enough degree
-        for i in 0..self.num_fri_layers_to_build() {
-
-            // Record the last evaluation vector.
-            if i == self.num_fri_layers_to_build() - 1 {
-                last_eval_vector = evaluations.clone();
-            }
-
+        // reduce the degree by folding_factor at each iteration, committing every layer but the
+        // last, until the remaining polynomial has small enough degree
+        for _ in 0..self.num_fri_layers_to_build() - 1 {
             match self.folding_factor() {
                 2 => self.build_layer::<2>(channel, &mut evaluations),
                 4 => self.build_layer::<4>(channel, &mut evaluations),
@@ -129,7 +149,7 @@ where
             }
         }
 
-        last_eval_vector
+        evaluations
     }
 
     
@@ -158,6 +178,71 @@ where
             ));
     }
 
+    // BATCH-FRI ORACLE
+    // --------------------------------------------------------------------------------------------
+    /// Executes the commit phase of the FRI protocol over a *batch* of polynomials whose
+    /// evaluation domains may differ in size, as in plonky2's `batch_fri` module.
+    ///
+    /// `inputs` are first grouped by domain size via [group_by_domain_size]. The largest group
+    /// seeds the running codeword, with its members combined using consecutive powers of a single
+    /// reducing factor drawn from the channel. Folding then proceeds exactly as in
+    /// [build_layers()](FoldingProver::build_layers()), except that whenever the current folded
+    /// domain size matches the next-largest group's domain size, that group's (likewise reduced)
+    /// evaluations are added into the running codeword before folding continues. This removes the
+    /// need to pad every worker's input up to a single common domain size.
+    ///
+    /// As in [build_layers](Self::build_layers), the final layer's evaluations are returned
+    /// uncommitted, since the master prover commits every worker's last layer, one function
+    /// commitment per worker, instead of each worker committing its own.
+    ///
+    /// Returns the final folded evaluation vector.
+    ///
+    /// # Panics
+    /// Panics if the prover state is dirty (the vector of layers is not empty), or if `inputs` is
+    /// empty.
+    pub fn build_layers_heterogeneous(&mut self, channel: &mut C, inputs: Vec<Vec<E>>) -> Vec<E> {
+        assert!(
+            self.layers.is_empty(),
+            "a prior proof generation request has not been completed yet"
+        );
+        assert!(!inputs.is_empty(), "a batch-FRI oracle needs at least one input polynomial");
+
+        let mut groups = group_by_domain_size(inputs).into_iter();
+
+        // the largest group seeds the running codeword
+        let (mut domain_size, first_group) = groups.next().expect("at least one group");
+        let reducing_alpha = channel.draw_fri_alpha();
+        let mut evaluations = combine_same_size_polys(&first_group, reducing_alpha);
+
+        let mut groups = groups.peekable();
+
+        for _ in 0..self.num_fri_layers_to_build() - 1 {
+            match self.folding_factor() {
+                2 => self.build_layer::<2>(channel, &mut evaluations),
+                4 => self.build_layer::<4>(channel, &mut evaluations),
+                8 => self.build_layer::<8>(channel, &mut evaluations),
+                16 => self.build_layer::<16>(channel, &mut evaluations),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            }
+            domain_size /= self.folding_factor();
+
+            // inject every group whose domain size now matches the folded codeword
+            while let Some((group_domain_size, _)) = groups.peek() {
+                if *group_domain_size != domain_size {
+                    break;
+                }
+                let (_, group) = groups.next().expect("peeked group must exist");
+                let group_evaluations = combine_same_size_polys(&group, reducing_alpha);
+                for (folded, injected) in evaluations.iter_mut().zip(group_evaluations.iter()) {
+                    *folded += *injected;
+                }
+            }
+        }
+
+        assert!(groups.next().is_none(), "a group's domain size was never reached while folding");
+
+        evaluations
+    }
 
     // QUERY PHASE
     // --------------------------------------------------------------------------------------------
@@ -169,11 +254,19 @@ where
     /// [crate::FriProver] and a [FoldingProver] is that a [FoldingProver] does not need to deal
     /// with the remainder.
     ///
+    /// `pow_nonce` is the proof-of-work nonce found by the caller (if [grinding_factor](FoldingOptions::grinding_factor)
+    /// is non-zero) just before `positions` were drawn from its own transcript; it is carried
+    /// into the returned [FoldingProof] unchanged so the verifier can re-check it. Pass 0 when
+    /// `positions` were not drawn from this prover's own transcript at all, as in the
+    /// Fold-and-Batch protocol where a worker's positions are instead folded down from the
+    /// master's already-grinded ones.
+    ///
     /// # Panics
     /// Panics is the prover state is clean (no FRI layers have been build yet).
-    pub fn build_proof(&mut self, input: &[E], positions: &[usize]) -> (FoldingProof, Vec<E>) {
+    pub fn build_proof(&mut self, input: &[E], positions: &[usize], pow_nonce: u64) -> (FoldingProof, Vec<E>) {
 
         let mut layers = Vec::with_capacity(self.layers.len());
+        let mut folding_schedule = Vec::with_capacity(self.layers.len());
 
         if !self.layers.is_empty() {
             let mut positions = positions.to_vec();
@@ -195,6 +288,7 @@ where
                 };
 
                 layers.push(proof_layer);
+                folding_schedule.push(folding_factor as u8);
                 domain_size /= folding_factor;
             }
         }
@@ -202,139 +296,633 @@ where
         // Comptute the evaluations of this prover's local polynomial at all the query positions.
         let evaluation_vector = positions.iter().map(|&p| input[p]).collect::<Vec<_>>();
 
-        (FoldingProof::new(layers), evaluation_vector)
-    } 
+        (FoldingProof::new(layers, folding_schedule, pow_nonce), evaluation_vector)
+    }
+
+    // REMAINDER CERTIFICATION
+    // --------------------------------------------------------------------------------------------
+    /// Recovers the coefficients of the polynomial underlying `last_eval_vector` -- the raw,
+    /// uncommitted evaluations [build_layers](Self::build_layers) returns for this prover's final
+    /// layer -- via barycentric Lagrange interpolation over this prover's
+    /// [final_domain_size](FoldingOptions::final_domain_size), and asserts that every coefficient
+    /// past [last_poly_max_degree](FoldingOptions::last_poly_max_degree) is zero.
+    ///
+    /// Unlike recovering a polynomial's coefficients from its evaluations with an inverse FFT (as
+    /// [crate::folding_pcs::FoldingPcs] does to evaluate a committed polynomial at an arbitrary
+    /// point), this computes the barycentric weights directly: every evaluation node `x_j`'s
+    /// denominator `∏_{k≠j}(x_j - x_k)` is
+    /// computed independently, every node's denominator is then inverted together in a single
+    /// pass via the same running-product trick used elsewhere in this crate (one field inversion
+    /// total instead of one per node), and the weighted Lagrange basis polynomials are finally
+    /// summed into the result. This lets a worker certify, on its own, that its last layer really
+    /// is a low-degree codeword, rather than only the master's (or a verifier's) later queries
+    /// catching a dishonest worker.
+    ///
+    /// Returns `last_poly_max_degree() + 1` coefficients, in ascending order (the returned
+    /// vector's `i`-th entry is the coefficient of `x^i`).
+    ///
+    /// # Panics
+    /// Panics if `last_eval_vector.len()` does not equal
+    /// [final_domain_size](FoldingOptions::final_domain_size), or if the interpolated polynomial's
+    /// degree exceeds [last_poly_max_degree](FoldingOptions::last_poly_max_degree).
+    pub fn interpolate_last_layer(&self, last_eval_vector: &[E]) -> Vec<E> {
+        let domain_size = self.options.final_domain_size();
+        assert_eq!(
+            last_eval_vector.len(),
+            domain_size,
+            "last_eval_vector must contain exactly final_domain_size evaluations"
+        );
+
+        let nodes = domain_values(domain_size, self.domain_offset());
+
+        let mut denominators = Vec::with_capacity(domain_size);
+        for (j, &x_j) in nodes.iter().enumerate() {
+            let mut denominator = E::ONE;
+            for (k, &x_k) in nodes.iter().enumerate() {
+                if j != k {
+                    denominator *= x_j - x_k;
+                }
+            }
+            denominators.push(denominator);
+        }
+        let weights = batch_inverse(&denominators);
+
+        // Z(x) = the monic polynomial vanishing on every node, built up one linear factor at a
+        // time so that each node's Lagrange basis polynomial can be recovered below by dividing
+        // Z(x) back out by that node's own factor.
+        let mut vanishing_poly = vec![E::ONE];
+        for &x_j in &nodes {
+            vanishing_poly = multiply_by_linear_factor(&vanishing_poly, x_j);
+        }
+
+        let mut coefficients = vec![E::ZERO; domain_size];
+        for ((&x_j, &y_j), &weight_j) in nodes.iter().zip(last_eval_vector).zip(&weights) {
+            let basis_poly = divide_by_linear_factor(&vanishing_poly, x_j);
+            let scale = y_j * weight_j;
+            for (coefficient, &basis_coefficient) in coefficients.iter_mut().zip(&basis_poly) {
+                *coefficient += scale * basis_coefficient;
+            }
+        }
+
+        let last_poly_max_degree = self.options.last_poly_max_degree();
+        assert!(
+            coefficients[last_poly_max_degree + 1..].iter().all(|&c| c == E::ZERO),
+            "last layer is not actually a polynomial of degree at most {last_poly_max_degree}"
+        );
+        coefficients.truncate(last_poly_max_degree + 1);
+        coefficients
+    }
 }
 
+/// Returns every point of the LDE domain of size `domain_size` shifted by `domain_offset`, in
+/// order, i.e. `domain_offset * g^i` for `i` in `0..domain_size`, where `g` is the domain's
+/// generator.
+fn domain_values<E: FieldElement>(domain_size: usize, domain_offset: E::BaseField) -> Vec<E> {
+    let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+    let mut values = Vec::with_capacity(domain_size);
+    let mut x = domain_offset;
+    for _ in 0..domain_size {
+        values.push(E::from(x));
+        x *= g;
+    }
+    values
+}
 
+/// Inverts every element of `values` using a single field inversion, via the standard
+/// running-product trick.
+///
+/// # Panics
+/// Panics if any element of `values` is zero.
+fn batch_inverse<E: FieldElement>(values: &[E]) -> Vec<E> {
+    assert!(values.iter().all(|v| *v != E::ZERO), "cannot invert a zero field element");
+
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values {
+        prefix_products.push(acc);
+        acc *= value;
+    }
+
+    let mut inv_acc = acc.inv();
+    let mut result = vec![E::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = inv_acc * prefix_products[i];
+        inv_acc *= values[i];
+    }
+    result
+}
+
+/// Multiplies the polynomial represented by `coefficients` (ascending: `coefficients[i]` is the
+/// coefficient of `x^i`) by `(x - root)`, growing its degree by one.
+fn multiply_by_linear_factor<E: FieldElement>(coefficients: &[E], root: E) -> Vec<E> {
+    let mut result = vec![E::ZERO; coefficients.len() + 1];
+    for (i, &c) in coefficients.iter().enumerate() {
+        result[i + 1] += c;
+        result[i] -= c * root;
+    }
+    result
+}
+
+/// Divides the polynomial represented by `coefficients` (ascending) by `(x - root)`, assuming
+/// `root` is an exact root of that polynomial (so the remainder is zero), returning a quotient
+/// one degree lower.
+fn divide_by_linear_factor<E: FieldElement>(coefficients: &[E], root: E) -> Vec<E> {
+    let degree = coefficients.len() - 1;
+    let mut quotient = vec![E::ZERO; degree];
+    let mut carry = E::ZERO;
+    for i in (0..degree).rev() {
+        carry = coefficients[i + 1] + carry * root;
+        quotient[i] = carry;
+    }
+    quotient
+}
+
+
+/// Runs the FRI commit phase for each worker node on its own local input polynomial.
+///
+/// Unlike a single shared `worker_domain_size`, `worker_domain_sizes` holds one domain size per
+/// worker, in the same order as `inputs`, so that workers holding traces of differing lengths
+/// can be aggregated into a single Fold-and-Batch proof, as in plonky2's
+/// `FriBatchInfo`/`FriInstanceInfo`.
+///
+/// `blinding_degree`, when `Some`, enables zero-knowledge hiding: every worker adds a random
+/// blinding polynomial of that degree to its last (batched) layer before it is handed off to the
+/// master, and the same blinding evaluation vectors are returned alongside the usual outputs so
+/// the master can combine them the same way it combines the (now-blinded) worker last layers,
+/// and send the combined result to the verifier for cancellation.
+///
+/// `worker_last_poly_max_degrees` holds one ending degree bound per worker, in the same order as
+/// `inputs`, so that workers whose own traces stop at differing degrees (and therefore hand the
+/// master differently-sized last layers) can still be batched together; the master folds the
+/// running codeword down to each worker's own domain size before injecting it (see
+/// [BatchedFriProver::fold_and_batch_master_commit](crate::BatchedFriProver::fold_and_batch_master_commit)),
+/// just as [FoldingProver::build_layers_heterogeneous](crate::FoldingProver::build_layers_heterogeneous)
+/// does for a single worker's own inputs of differing sizes.
+///
+/// `interpolate_remainder`, when `true`, configures every worker with
+/// [FoldingOptions::with_interpolated_remainder](crate::FoldingOptions::with_interpolated_remainder):
+/// alongside its usual (possibly blinded) last-layer evaluations, each worker also interpolates
+/// that layer into explicit coefficients via [FoldingProver::interpolate_last_layer], returned as
+/// the fifth element of the tuple (`None` per worker when this is `false`) for the caller to
+/// attach to the proof so the verifier can check them directly instead of opening a Merkle proof
+/// against that worker's function commitment. This does not change what the master commits to:
+/// every worker's function commitment and opening are still built and sent exactly as before, so
+/// a verifier that skips the opening for an interpolated worker is trading away CPU time rather
+/// than proof bytes.
+///
+/// # Panics
+/// Panics if `worker_domain_sizes.len()` or `worker_last_poly_max_degrees.len()` does not equal
+/// `num_poly`.
 pub fn fold_and_batch_worker_commit<E, H, R, V>(
     inputs: &Vec<Vec<E>>,
     num_poly: usize,
     lde_blowup: usize,
     folding_factor: usize,
-    worker_domain_size: usize,
-    worker_last_poly_max_degree: usize,
-    num_queries: usize
-) -> (Vec<FoldingProver<E, DefaultProverChannel<E, H, R>, H, V>>, Vec<Vec<H::Digest>>, Vec<Vec<E>>)
-where 
+    worker_domain_sizes: &[usize],
+    worker_last_poly_max_degrees: &[usize],
+    num_queries: usize,
+    blinding_degree: Option<usize>,
+    interpolate_remainder: bool,
+) -> (Vec<FoldingProver<E, DefaultProverChannel<E, H, R>, H, V>>, Vec<Vec<H::Digest>>, Vec<Vec<E>>, Vec<Vec<E>>, Vec<Option<Vec<E>>>)
+where
     E: FieldElement + StarkField,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
 {
+    assert_eq!(
+        worker_domain_sizes.len(),
+        num_poly,
+        "a domain size must be provided for every worker node"
+    );
+    assert_eq!(
+        worker_last_poly_max_degrees.len(),
+        num_poly,
+        "an ending degree bound must be provided for every worker node"
+    );
 
-     // Instantiate the worker nodes.
+     // Instantiate the worker nodes, each with its own domain size and ending degree bound.
      let mut worker_nodes = Vec::with_capacity(num_poly);
-     let worker_options = FoldingOptions::new(lde_blowup, folding_factor, worker_domain_size, worker_last_poly_max_degree);
-     for _ in 0..num_poly {
-         worker_nodes.push(FoldingProver::<E, DefaultProverChannel<E, H, R>, H, V>::new(worker_options.clone()));
+     for (&worker_domain_size, &worker_last_poly_max_degree) in worker_domain_sizes.iter().zip(worker_last_poly_max_degrees.iter()) {
+         let worker_options = match blinding_degree {
+             Some(blinding_degree) => FoldingOptions::new_zk(lde_blowup, folding_factor, worker_domain_size, worker_last_poly_max_degree, blinding_degree),
+             None => FoldingOptions::new(lde_blowup, folding_factor, worker_domain_size, worker_last_poly_max_degree),
+         }
+         .expect("invalid folding options");
+         let worker_options = if interpolate_remainder {
+             worker_options.with_interpolated_remainder()
+         } else {
+             worker_options
+         };
+         worker_nodes.push(FoldingProver::<E, DefaultProverChannel<E, H, R>, H, V>::new(worker_options));
      }
 
-    // Each worker node executes the FRI commit phase on their local input polynomial.
+    // Each worker node executes the FRI commit phase on their local input polynomial. Every
+    // worker's commit phase is independent of every other's -- each gets its own freshly
+    // constructed, deterministically seeded DefaultProverChannel, so running them out of order
+    // or concurrently cannot cross-contaminate any worker's transcript -- so with the
+    // `concurrent` feature enabled this runs over `rayon`'s thread pool; results are collected
+    // back in worker order so the proof produced is bit-identical to the sequential path.
     let num_worker = worker_nodes.len();
-    let mut worker_layer_commitments = Vec::with_capacity(num_worker);
-    let mut batched_fri_inputs = Vec::with_capacity(num_worker);
-    for (i, node) in worker_nodes.iter_mut().enumerate() {
+    let commit_worker = |i: usize, node: &mut FoldingProver<E, DefaultProverChannel<E, H, R>, H, V>| {
+        // Prepare a ProverChannel for the worker node, sized to its own domain.
+        let mut worker_channel = DefaultProverChannel::<E, H, R>::new(worker_domain_sizes[i], num_queries);
 
-        // Prepare a ProverChannel for the worker node
-        let mut worker_channel = DefaultProverChannel::<E, H, R>::new(worker_domain_size, num_queries);
-        
         // Execute the commit phase for the worker node.
-        let last_eval_vector = node.build_layers(&mut worker_channel, inputs[i].clone());
+        let mut last_eval_vector = node.build_layers(&mut worker_channel, inputs[i].clone());
+
+        // Blind the worker's last layer before it is batched by the master, so that neither the
+        // master's function commitment to this worker's last layer nor the evaluations opened at query positions
+        // reveal this worker's actual last-layer evaluations.
+        let blinding = match blinding_degree {
+            Some(blinding_degree) => random_blinding_evaluations(blinding_degree, last_eval_vector.len()),
+            None => vec![E::ZERO; last_eval_vector.len()],
+        };
+        for (value, &blind) in last_eval_vector.iter_mut().zip(blinding.iter()) {
+            *value += blind;
+        }
+
+        // Interpolate the (possibly now-blinded) last layer into explicit coefficients when this
+        // worker was configured to, so the caller can attach them to the proof in place of a
+        // Merkle commitment/opening for this worker's function layer.
+        let remainder_coefficients = if node.options().interpolates_remainder() {
+            Some(node.interpolate_last_layer(&last_eval_vector))
+        } else {
+            None
+        };
+
+        (worker_channel.layer_commitments().to_vec(), last_eval_vector, blinding, remainder_coefficients)
+    };
+
+    #[cfg(feature = "concurrent")]
+    let results: Vec<_> = worker_nodes
+        .par_iter_mut()
+        .enumerate()
+        .map(|(i, node)| commit_worker(i, node))
+        .collect();
+    #[cfg(not(feature = "concurrent"))]
+    let results: Vec<_> = worker_nodes
+        .iter_mut()
+        .enumerate()
+        .map(|(i, node)| commit_worker(i, node))
+        .collect();
+
+    let mut worker_layer_commitments = Vec::with_capacity(num_worker);
+    let mut batched_fri_inputs = Vec::with_capacity(num_worker);
+    let mut blinding_evaluations = Vec::with_capacity(num_worker);
+    let mut worker_remainder_coefficients = Vec::with_capacity(num_worker);
+    for (layer_commitments, last_eval_vector, blinding, remainder_coefficients) in results {
+        worker_layer_commitments.push(layer_commitments);
         batched_fri_inputs.push(last_eval_vector);
-        worker_layer_commitments.push(worker_channel.layer_commitments().to_vec());
+        blinding_evaluations.push(blinding);
+        worker_remainder_coefficients.push(remainder_coefficients);
     }
 
-    (worker_nodes, worker_layer_commitments, batched_fri_inputs)
+    (worker_nodes, worker_layer_commitments, batched_fri_inputs, blinding_evaluations, worker_remainder_coefficients)
+}
+
+/// Selects every worker's blinding contribution at `query_positions` and combines them using
+/// `batched_fri_challenge`, exactly as the master combines the (now-blinded) worker last layers
+/// themselves, so that the result is the blinding contribution baked into the batched
+/// evaluations the verifier opens at those same positions.
+///
+/// `worker_function_domain_sizes` holds one domain size per worker, the size of the domain its
+/// blinding evaluations (and last layer) actually live on, which may differ from that worker's
+/// starting `worker_domain_sizes` once workers end their local folding at differing degrees.
+///
+/// Returns an empty vector when `worker_blinding_evaluations` holds no evaluations (the non-ZK
+/// path), in which case there is nothing for the verifier to cancel out.
+fn combine_blinding_evaluations<E: FieldElement>(
+    worker_blinding_evaluations: &[Vec<E>],
+    worker_function_domain_sizes: &[usize],
+    sampling_domain_size: usize,
+    folding_factor: usize,
+    query_positions: &[usize],
+    batched_fri_challenge: E,
+) -> Vec<E> {
+    if worker_blinding_evaluations.iter().all(|blinding| blinding.is_empty()) {
+        return Vec::new();
+    }
+
+    let blinding_at_queries: Vec<Vec<E>> = worker_blinding_evaluations
+        .iter()
+        .zip(worker_function_domain_sizes.iter())
+        .map(|(blinding, &worker_function_domain_size)| {
+            let positions = fold_query_positions(query_positions, sampling_domain_size, worker_function_domain_size, folding_factor);
+            positions.iter().map(|&p| blinding[p]).collect()
+        })
+        .collect();
+
+    combine_poly_evaluations(&blinding_at_queries, batched_fri_challenge)
 }
 
+/// Runs the FRI query phase for each worker node.
+///
+/// `query_positions` are sampled once over the shared `sampling_domain_size` (the largest domain
+/// among all worker nodes); a worker whose own domain is smaller folds those positions down to
+/// its own domain, the worker-specific number of times, before querying its layers.
 pub fn fold_and_batch_worker_query<E, H, V, R>(
     inputs: &Vec<Vec<E>>,
     worker_nodes: &mut Vec<FoldingProver<E, DefaultProverChannel<E, H, R>, H, V>>,
+    worker_domain_sizes: &[usize],
+    sampling_domain_size: usize,
+    folding_factor: usize,
     query_positions: &[usize],
-) -> (Vec<FoldingProof>, Vec<Vec<E>>) 
-where 
+) -> (Vec<FoldingProof>, Vec<Vec<E>>)
+where
     E: FieldElement + StarkField,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
 {
-    let num_worker = worker_nodes.len();
-    let mut folding_proofs = Vec::with_capacity(num_worker);
-    let mut worker_evaluations = Vec::with_capacity(num_worker);
-    for i in 0..num_worker {
-        let (folding_proof, evaluation_vector) = worker_nodes[i].build_proof(&inputs[i], &query_positions);
-        folding_proofs.push(folding_proof);
-        worker_evaluations.push(evaluation_vector);
+    // Every worker's query phase is independent of every other's, so with the `concurrent`
+    // feature enabled this runs over `rayon`'s thread pool; results are collected back in worker
+    // order so the proof produced is bit-identical to the sequential path.
+    let query_worker = |i: usize, node: &mut FoldingProver<E, DefaultProverChannel<E, H, R>, H, V>| {
+        let positions = fold_query_positions(query_positions, sampling_domain_size, worker_domain_sizes[i], folding_factor);
+        // Positions are folded down from the master's own (already-grinded) sampled positions
+        // rather than drawn from this worker's transcript, so there is no worker-level nonce to
+        // carry here.
+        node.build_proof(&inputs[i], &positions, 0)
+    };
+
+    #[cfg(feature = "concurrent")]
+    let results: Vec<_> = worker_nodes
+        .par_iter_mut()
+        .enumerate()
+        .map(|(i, node)| query_worker(i, node))
+        .collect();
+    #[cfg(not(feature = "concurrent"))]
+    let results: Vec<_> = worker_nodes
+        .iter_mut()
+        .enumerate()
+        .map(|(i, node)| query_worker(i, node))
+        .collect();
+
+    results.into_iter().unzip()
+}
+
+/// Folds `positions`, sampled over a domain of size `from_domain_size`, down to a domain of
+/// size `to_domain_size`.
+///
+/// Before heterogeneous worker degree bounds, every worker folded query positions down from one
+/// shared domain size; now each worker's own (possibly smaller) domain size determines its own
+/// fold count, mirroring the analogous helper on the verifier side.
+pub(crate) fn fold_query_positions(positions: &[usize], from_domain_size: usize, to_domain_size: usize, folding_factor: usize) -> Vec<usize> {
+    let mut positions = positions.to_vec();
+    let mut current_domain_size = from_domain_size;
+    while current_domain_size > to_domain_size {
+        positions = fold_positions(&positions, current_domain_size, folding_factor);
+        current_domain_size /= folding_factor;
     }
-    (folding_proofs, worker_evaluations)
+    positions
 }
 
 
+// BATCH-FRI ORACLE HELPERS
+// ================================================================================================
+
+/// Groups `inputs` by their evaluation domain size (i.e., the length of each polynomial's
+/// evaluation vector, a power of two) and returns the resulting groups ordered from the largest
+/// domain size to the smallest.
+///
+/// This is the grouping step of the batch-FRI oracle used by
+/// [FoldingProver::build_layers_heterogeneous]: polynomials that share a domain size are combined
+/// together, while polynomials living on smaller domains are folded into the running codeword
+/// only once folding has reduced it down to their size.
+pub fn group_by_domain_size<E: FieldElement>(inputs: Vec<Vec<E>>) -> Vec<(usize, Vec<Vec<E>>)> {
+    let mut groups: Vec<(usize, Vec<Vec<E>>)> = Vec::new();
+    for input in inputs {
+        match groups.iter_mut().find(|(domain_size, _)| *domain_size == input.len()) {
+            Some((_, bucket)) => bucket.push(input),
+            None => groups.push((input.len(), vec![input])),
+        }
+    }
+    groups.sort_unstable_by(|a, b| b.0.cmp(&a.0));
+    groups
+}
+
+/// Combines a group of same-length polynomial evaluation vectors into a single evaluation vector
+/// using consecutive powers of `reducing_alpha`: `polys[0] + alpha * polys[1] + alpha^2 *
+/// polys[2] + ...`.
+///
+/// Every output position is combined independently of every other, so with the `concurrent`
+/// feature enabled this runs over `rayon`'s thread pool via [utils::iterators]; without it, the
+/// same code runs single-threaded and produces bit-identical output.
+///
+/// # Panics
+/// Panics if `polys` is empty.
+fn combine_same_size_polys<E: FieldElement>(polys: &[Vec<E>], reducing_alpha: E) -> Vec<E> {
+    assert!(!polys.is_empty(), "a group must contain at least one polynomial");
+
+    let powers: Vec<E> = core::iter::successors(Some(E::ONE), |&p| Some(p * reducing_alpha))
+        .take(polys.len())
+        .collect();
+    let combine_position = |j: usize| {
+        let mut acc = E::ZERO;
+        for (poly, &power) in polys.iter().zip(powers.iter()) {
+            acc += power * poly[j];
+        }
+        acc
+    };
+
+    let domain_size = polys[0].len();
+    #[cfg(feature = "concurrent")]
+    let combined = (0..domain_size).into_par_iter().map(combine_position).collect();
+    #[cfg(not(feature = "concurrent"))]
+    let combined = (0..domain_size).map(combine_position).collect();
+
+    combined
+}
+
+
+/// `worker_domain_sizes` holds one domain size per worker node, in the same order the worker
+/// nodes' `inputs` are given, so that workers holding traces of differing lengths can be
+/// aggregated into a single Fold-and-Batch proof.
+///
+/// `blinding_degree`, when `Some`, enables zero-knowledge hiding as described on
+/// [fold_and_batch_worker_commit]: every worker blinds its last layer before the master batches
+/// it, and the combined blinding contribution at the query positions is carried in the returned
+/// proof so the verifier can cancel it back out.
+///
+/// The returned proof also carries the master's remainder as evaluations over a small canonical
+/// point set, in addition to the coefficients already embedded in the FRI proof, so the verifier
+/// can cross-check it via Lagrange interpolation (see [FoldAndBatchProof::with_master_remainder]).
+///
+/// `worker_evaluation_parity_count`, when greater than 0, enables erasure-coded dispersal of the
+/// worker evaluations: that many parity vectors are derived from every worker's evaluations via
+/// [encode_worker_evaluation_parity] and attached to the proof, so that a verifier missing up to
+/// `worker_evaluation_parity_count` of the `num_poly` worker evaluation vectors can rebuild them
+/// instead of rejecting the proof outright. This requires every worker to share the same domain
+/// size (so that the query positions, folded from the shared sampling domain down to each
+/// worker's own domain, give every worker an evaluation vector of the same length); pass 0 to
+/// leave worker evaluations uncoded.
+///
+/// `worker_last_poly_max_degrees` holds one ending degree bound per worker, in the same order as
+/// `worker_domain_sizes`, so workers may stop folding locally at different degrees before the
+/// master batches their last layers together (see [fold_and_batch_worker_commit]).
+///
+/// `interpolate_remainder`, when `true`, has every worker interpolate its last layer into
+/// explicit coefficients (see [FoldingOptions::with_interpolated_remainder]) and attaches them to
+/// the returned proof, so the verifier can check that worker's last layer directly instead of
+/// opening a Merkle proof against its function commitment (see [fold_and_batch_worker_commit]).
+///
+/// # Panics
+/// Panics if `worker_domain_sizes` is empty, if `worker_last_poly_max_degrees.len()` does not
+/// equal `worker_domain_sizes.len()`, or if `worker_evaluation_parity_count` is greater than 0 and
+/// the worker evaluation vectors do not all have the same length.
 pub fn fold_and_batch_prove<E, H, R, V>(
     inputs: Vec<Vec<E>>,
-    num_poly: usize, 
+    num_poly: usize,
     lde_blowup: usize,
     folding_factor: usize,
-    worker_domain_size: usize,
-    worker_last_poly_max_degree: usize,
+    worker_domain_sizes: Vec<usize>,
+    worker_last_poly_max_degrees: Vec<usize>,
     master_domain_size: usize,
     master_options: FriOptions,
-    num_queries: usize
-) -> FoldAndBatchProof<H> 
-where 
+    num_queries: usize,
+    blinding_degree: Option<usize>,
+    worker_evaluation_parity_count: usize,
+    interpolate_remainder: bool,
+) -> FoldAndBatchProof<H>
+where
     E: FieldElement + StarkField,
     H: ElementHasher<BaseField = E::BaseField>,
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
 {
+    // Query positions for Fold-and-Batch are sampled once over the largest worker domain, since
+    // it is a superset of every (possibly smaller) worker's own domain.
+    let sampling_domain_size = *worker_domain_sizes
+        .iter()
+        .max()
+        .expect("at least one worker node is required");
 
     // ------------------------ Step 1: worker commit phase --------------------------
     // Each worker node executes the FRI commit phase on their local input polynomial.
 
-    let (mut worker_nodes, worker_layer_commitments, batched_fri_inputs) = 
+    let (mut worker_nodes, worker_layer_commitments, batched_fri_inputs, blinding_evaluations, worker_remainder_coefficients) =
     fold_and_batch_worker_commit(
-        &inputs, 
-        num_poly, 
-        lde_blowup, 
-        folding_factor, 
-        worker_domain_size, 
-        worker_last_poly_max_degree, 
-        num_queries
+        &inputs,
+        num_poly,
+        lde_blowup,
+        folding_factor,
+        &worker_domain_sizes,
+        &worker_last_poly_max_degrees,
+        num_queries,
+        blinding_degree,
+        interpolate_remainder,
     );
-  
+
 
     // ------------------------ Step 2: master commit phase ----------------------------
     // The master prover executes the commit phase of batched FRI and produces the query
     // positions using Fiat-Shamir.
 
     // Instantiate the master prover.
-    let mut master_prover = BatchedFriProver::<E, H, V, R>::new(master_options);
+    let mut master_prover =
+        BatchedFriProver::<E, H, V, RandomCoinTranscript<E, H, R>>::new(master_options, RandomCoinTranscript::new());
 
-    let (batched_evaluations, query_positions) = master_prover.fold_and_batch_master_commit(
-        worker_domain_size, 
-        num_queries, 
+    let (batched_evaluations, query_positions, batched_fri_challenge) = master_prover.fold_and_batch_master_commit(
+        sampling_domain_size,
+        num_queries,
         &worker_layer_commitments,
         batched_fri_inputs);
 
-    
+
     // -------------------------- Step 3: worker query phase --------------------------------
-    // Each worker node generates the FRI folding proof proving that the folding of its local 
+    // Each worker node generates the FRI folding proof proving that the folding of its local
     // polynomial was done correctly.
-    let (folding_proofs, worker_evaluations) = 
-        fold_and_batch_worker_query::<E, H, V, R>(&inputs, &mut worker_nodes, &query_positions);
+    let (folding_proofs, worker_evaluations) = fold_and_batch_worker_query::<E, H, V, R>(
+        &inputs,
+        &mut worker_nodes,
+        &worker_domain_sizes,
+        sampling_domain_size,
+        folding_factor,
+        &query_positions,
+    );
+
+    // Combine every worker's blinding contribution at the query positions using the same
+    // reducing challenge the master used to batch the (now-blinded) last layers, so the
+    // verifier can subtract it from the batched evaluations it opens. Empty on the non-ZK path.
+    //
+    // The blinding evaluations themselves live at each worker's *ending* (function) domain size,
+    // not its starting `worker_domain_sizes[i]`, since they blind the last layer handed off to
+    // the master -- these only happened to coincide before workers could end their local folding
+    // at differing degrees.
+    let worker_function_domain_sizes: Vec<usize> = worker_last_poly_max_degrees
+        .iter()
+        .map(|&degree| lde_blowup * (degree + 1).next_power_of_two())
+        .collect();
+    let blinding_evaluations = combine_blinding_evaluations(
+        &blinding_evaluations,
+        &worker_function_domain_sizes,
+        sampling_domain_size,
+        folding_factor,
+        &query_positions,
+        batched_fri_challenge,
+    );
+
+    // Derive parity vectors from every worker's evaluations at the query positions, so a verifier
+    // missing up to `worker_evaluation_parity_count` worker evaluation vectors can rebuild them
+    // instead of rejecting the proof. Disabled when `worker_evaluation_parity_count` is 0.
+    let worker_evaluation_parity = if worker_evaluation_parity_count > 0 {
+        Some(encode_worker_evaluation_parity(&worker_evaluations, worker_evaluation_parity_count))
+    } else {
+        None
+    };
 
 
     // -------------------------- Step 4: master query phase --------------------------------
     // The master node executes the batched FRI query phase and assembles the Fold-and-Batch proof.
     let fold_and_batch_proof = master_prover.fold_and_batch_master_query(
-        worker_domain_size, 
-        master_domain_size, 
+        sampling_domain_size,
+        master_domain_size,
         worker_layer_commitments,
         query_positions,
-        folding_proofs, 
+        folding_proofs,
         worker_evaluations,
-        batched_evaluations);
+        batched_evaluations,
+        blinding_evaluations);
+
+    // Attach the master's remainder as evaluations over a small canonical point set, in addition
+    // to the coefficients already embedded in the FRI proof, so the verifier can cross-check it
+    // via Lagrange interpolation rather than trusting the transmitted coefficients alone. This can
+    // only be computed now, once the master's FRI query phase has produced its final remainder.
+    let master_remainder_coefficients = fold_and_batch_proof
+        .fri_proof()
+        .parse_remainder::<E>()
+        .expect("failed to parse the master's FRI remainder coefficients");
+    let master_remainder_points: Vec<E> = core::iter::successors(Some(E::ZERO), |&p| Some(p + E::ONE))
+        .take(master_remainder_coefficients.len())
+        .collect();
+    let master_remainder_evaluations = master_remainder_points
+        .iter()
+        .map(|&x| evaluate_poly_horner(&master_remainder_coefficients, x))
+        .collect();
+
+    let fold_and_batch_proof = fold_and_batch_proof.with_master_remainder(master_remainder_points, master_remainder_evaluations);
+
+    // Attach each worker's interpolated remainder coefficients, if any, so the verifier can check
+    // that worker's last layer directly instead of opening a Merkle proof against its function
+    // commitment. A worker that was not configured with `interpolate_remainder` contributes an
+    // empty entry here, which the verifier reads as "verify this worker the usual way".
+    let worker_remainder_coefficients: Vec<Vec<E>> = worker_remainder_coefficients
+        .into_iter()
+        .map(|coefficients| coefficients.unwrap_or_default())
+        .collect();
+    let fold_and_batch_proof = fold_and_batch_proof.with_worker_remainder_coefficients(worker_remainder_coefficients);
+
+    match worker_evaluation_parity {
+        Some((parity_evaluations, parity_points)) => {
+            fold_and_batch_proof.with_worker_evaluation_parity(parity_evaluations, parity_points)
+        }
+        None => fold_and_batch_proof,
+    }
+}
 
-    fold_and_batch_proof
+/// Evaluates the polynomial with `coefficients` (lowest degree first) at `x` using Horner's
+/// method.
+fn evaluate_poly_horner<E: FieldElement>(coefficients: &[E], x: E) -> E {
+    coefficients.iter().rev().fold(E::ZERO, |acc, &coefficient| acc * x + coefficient)
 }
\ No newline at end of file
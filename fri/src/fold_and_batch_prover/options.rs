@@ -1,6 +1,8 @@
+use core::fmt;
+
 use math::StarkField;
 
-// FOLDING OPTIONS 
+// FOLDING OPTIONS
 // ================================================================================================
 
 /// FRI protocol config options for folding proof generation and verification. This struct is
@@ -11,36 +13,137 @@ pub struct FoldingOptions {
     blowup_factor: usize,
     domain_size: usize,
     last_poly_max_degree: usize,
+    zk: bool,
+    blinding_degree: usize,
+    grinding_factor: u32,
+    interpolate_remainder: bool,
 }
 
 impl FoldingOptions {
     /// Returns a new [FoldingOptions] struct instantiated with the specified parameters.
-    /// `last_poly_max_degree` is the maximum degree of the polynomial at the last FRI layer 
+    /// `last_poly_max_degree` is the maximum degree of the polynomial at the last FRI layer
     /// of a [FoldingProver](crate::FoldingProver) using this [FoldingOptions].
     ///
-    /// # Panics
-    /// Panics if:
+    /// This constructor leaves zero-knowledge hiding disabled; use [new_zk](Self::new_zk) for a
+    /// [FoldingProver](crate::FoldingProver) that should blind its last layer before it is
+    /// batched by the master node.
+    ///
+    /// # Errors
+    /// Returns an error if:
     /// - `blowup_factor` is not a power of two.
     /// - `folding_factor` is not 2, 4, 8, or 16.
-    pub fn new(blowup_factor: usize, folding_factor: usize, domain_size: usize, last_poly_max_degree: usize) -> Self {
-        // TODO: change panics to errors
-        assert!(
-            blowup_factor.is_power_of_two(),
-            "blowup factor must be a power of two, but was {blowup_factor}"
-        );
-        assert!(
-            folding_factor == 2
-                || folding_factor == 4
-                || folding_factor == 8
-                || folding_factor == 16,
-            "folding factor {folding_factor} is not supported"
-        );
-        FoldingOptions {
+    pub fn new(blowup_factor: usize, folding_factor: usize, domain_size: usize, last_poly_max_degree: usize) -> Result<Self, FoldingError> {
+        Self::new_impl(blowup_factor, folding_factor, domain_size, last_poly_max_degree, false, 0)
+    }
+
+    /// Returns a new [FoldingOptions] struct with zero-knowledge hiding enabled.
+    ///
+    /// A [FoldingProver](crate::FoldingProver) configured with the resulting options adds a
+    /// random blinding polynomial of degree at most `blinding_degree` to its last (batched)
+    /// layer before handing it off to the master node, so that the master's function commitment
+    /// to that layer and the evaluations opened at query positions no longer leak the worker's
+    /// actual last-layer evaluations.
+    ///
+    /// # Errors
+    /// Returns an error under the same conditions as [new](Self::new).
+    pub fn new_zk(blowup_factor: usize, folding_factor: usize, domain_size: usize, last_poly_max_degree: usize, blinding_degree: usize) -> Result<Self, FoldingError> {
+        Self::new_impl(blowup_factor, folding_factor, domain_size, last_poly_max_degree, true, blinding_degree)
+    }
+
+    /// Returns a copy of these options with `grinding_factor` set, so that a [FoldingPcs](crate::folding_pcs::FoldingPcs)
+    /// configured with them searches for a proof-of-work nonce of that many leading zero bits
+    /// before drawing the query positions of each opening, as [FriOptions::with_grinding_factor](crate::FriOptions::with_grinding_factor)
+    /// does for the master's batched FRI. A [FoldingProver](crate::FoldingProver) does not read
+    /// this value; it uses whatever nonce its caller passes in. A `grinding_factor` of 0 (the
+    /// default) disables grinding entirely.
+    pub fn with_grinding_factor(mut self, grinding_factor: u32) -> Self {
+        self.grinding_factor = grinding_factor;
+        self
+    }
+
+    /// Returns a copy of these options with remainder interpolation enabled, so that a
+    /// [FoldingProver](crate::FoldingProver) configured with them can call
+    /// [interpolate_last_layer](crate::FoldingProver::interpolate_last_layer) to certify, via
+    /// barycentric Lagrange interpolation, that its last layer's raw evaluations really are a
+    /// polynomial of degree at most `last_poly_max_degree` before handing them off to the master
+    /// node, rather than relying solely on the master's and verifier's later low-degree checks.
+    pub fn with_interpolated_remainder(mut self) -> Self {
+        self.interpolate_remainder = true;
+        self
+    }
+
+    /// Returns a new [FoldingOptions] derived from a target conjectured security level, together
+    /// with the number of queries needed to reach it, rather than requiring the caller to pick
+    /// `num_queries` by hand.
+    ///
+    /// Every FRI query independently catches a far-from-low-degree codeword with probability
+    /// roughly `1 - 1/blowup_factor`, contributing about `log2(blowup_factor)` bits of conjectured
+    /// security; `num_queries` is the smallest count whose combined contribution reaches
+    /// `target_bits`. This ignores proof-of-work grinding: the returned `num_queries` does not
+    /// account for whatever [with_grinding_factor](Self::with_grinding_factor) a caller applies to
+    /// the returned options afterwards, so a caller that does add grinding on top can lower
+    /// [num_queries](FoldingSecurityParams::num_queries) accordingly itself.
+    ///
+    /// This also enforces the proximity-gap constraint that the extension field must be large
+    /// enough, relative to the domain, to support the target security level at all: a field of
+    /// `field_bits` bits evaluated over a domain of `domain_size` only has `field_bits -
+    /// log2(domain_size)` bits of room before the claimed security level collapses.
+    ///
+    /// # Errors
+    /// Returns an error if `blowup_factor`/`folding_factor` are invalid (as in [new](Self::new)),
+    /// or if `field_bits` is too small, relative to `domain_size`, to support `target_bits` of
+    /// conjectured security.
+    pub fn for_security(
+        field_bits: u32,
+        target_bits: u32,
+        blowup_factor: usize,
+        folding_factor: usize,
+        domain_size: usize,
+        last_poly_max_degree: usize,
+    ) -> Result<FoldingSecurityParams, FoldingError> {
+        let domain_size_bits = domain_size.ilog2();
+        if field_bits <= domain_size_bits + target_bits {
+            return Err(FoldingError::InsufficientFieldSize { field_bits, domain_size_bits, target_bits });
+        }
+
+        let blowup_bits = blowup_factor.ilog2();
+        let num_queries = target_bits.div_ceil(blowup_bits) as usize;
+
+        let options = Self::new(blowup_factor, folding_factor, domain_size, last_poly_max_degree)?;
+
+        Ok(FoldingSecurityParams { options, num_queries })
+    }
+
+    fn new_impl(blowup_factor: usize, folding_factor: usize, domain_size: usize, last_poly_max_degree: usize, zk: bool, blinding_degree: usize) -> Result<Self, FoldingError> {
+        if !blowup_factor.is_power_of_two() {
+            return Err(FoldingError::InvalidBlowupFactor(blowup_factor));
+        }
+        if !matches!(folding_factor, 2 | 4 | 8 | 16) {
+            return Err(FoldingError::UnsupportedFoldingFactor(folding_factor));
+        }
+
+        Ok(FoldingOptions {
             folding_factor,
             blowup_factor,
             domain_size,
-            last_poly_max_degree
-        }
+            last_poly_max_degree,
+            zk,
+            blinding_degree,
+            grinding_factor: 0,
+            interpolate_remainder: false,
+        })
+    }
+
+    /// Returns `true` if the [FoldingProver](crate::FoldingProver) using these options should
+    /// blind its last layer with a random polynomial before it is batched by the master node.
+    pub fn zk(&self) -> bool {
+        self.zk
+    }
+
+    /// Returns the maximum degree of the random blinding polynomial added to the last layer
+    /// when [zk](Self::zk) is enabled. Unused otherwise.
+    pub fn blinding_degree(&self) -> usize {
+        self.blinding_degree
     }
 
     /// Returns the offset by which the evaluation domain is shifted.
@@ -79,6 +182,28 @@ impl FoldingOptions {
         self.domain_size
     }
 
+    /// Returns the number of leading zero bits a proof-of-work nonce must satisfy before a
+    /// [FoldingPcs](crate::folding_pcs::FoldingPcs) configured with these options draws the query
+    /// positions of an opening. A value of 0 disables grinding.
+    pub fn grinding_factor(&self) -> u32 {
+        self.grinding_factor
+    }
+
+    /// Returns `true` if a [FoldingProver](crate::FoldingProver) configured with these options
+    /// should interpolate its last layer into explicit coefficients via
+    /// [interpolate_last_layer](crate::FoldingProver::interpolate_last_layer) and send those
+    /// coefficients alongside the raw evaluations it still hands off to the master node.
+    pub fn interpolates_remainder(&self) -> bool {
+        self.interpolate_remainder
+    }
+
+    /// Returns the domain size of the last FRI layer, i.e. the domain size a
+    /// [FoldingProver](crate::FoldingProver) using these options folds `domain_size` down to
+    /// before handing its last layer off to the master node to be batched.
+    pub fn final_domain_size(&self) -> usize {
+        (self.last_poly_max_degree + 1).next_power_of_two() * self.blowup_factor
+    }
+
 
     /// Computes the number of FRI layers a [FoldingProver](crate::FoldingProver) using this [FoldingOptions]
     /// should build.
@@ -95,3 +220,65 @@ impl FoldingOptions {
         result + 1 // The number of FRI layers is the number of foldings needed + 1
     }
 }
+
+/// The result of [FoldingOptions::for_security]: a [FoldingOptions] together with the number of
+/// queries needed to reach the conjectured security level it was derived from, so that callers
+/// stop hand-picking `num_queries`.
+pub struct FoldingSecurityParams {
+    options: FoldingOptions,
+    num_queries: usize,
+}
+
+impl FoldingSecurityParams {
+    /// Returns the derived [FoldingOptions].
+    pub fn options(&self) -> &FoldingOptions {
+        &self.options
+    }
+
+    /// Returns the number of queries needed to reach the target security level passed to
+    /// [FoldingOptions::for_security].
+    pub fn num_queries(&self) -> usize {
+        self.num_queries
+    }
+}
+
+// FOLDING ERROR
+// ================================================================================================
+
+/// Errors that can occur when constructing a [FoldingOptions].
+#[derive(Debug, PartialEq, Eq)]
+pub enum FoldingError {
+    /// `blowup_factor` was not a power of two.
+    InvalidBlowupFactor(usize),
+    /// `folding_factor` was not one of the supported values (2, 4, 8, or 16).
+    UnsupportedFoldingFactor(usize),
+    /// The field was too small, relative to the domain, to support the target security level:
+    /// `field_bits` must exceed `domain_size_bits + target_bits`.
+    InsufficientFieldSize {
+        field_bits: u32,
+        domain_size_bits: u32,
+        target_bits: u32,
+    },
+}
+
+impl fmt::Display for FoldingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FoldingError::InvalidBlowupFactor(blowup_factor) => {
+                write!(f, "blowup factor must be a power of two, but was {blowup_factor}")
+            },
+            FoldingError::UnsupportedFoldingFactor(folding_factor) => {
+                write!(f, "folding factor {folding_factor} is not supported")
+            },
+            FoldingError::InsufficientFieldSize { field_bits, domain_size_bits, target_bits } => {
+                write!(
+                    f,
+                    "a field of {field_bits} bits over a domain of 2^{domain_size_bits} elements cannot support {target_bits} bits of conjectured security"
+                )
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FoldingError {}
@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
 use math::{fft, fields::f128::BaseElement, FieldElement, StarkField};
@@ -28,14 +28,16 @@ fn test_fold_and_batch_single_poly() {
     let num_queries = 50;
 
     let result = fold_and_batch_prove_verify_random(
-        degree_bound_e, 
-        lde_blowup_e, 
-        folding_factor_e, 
-        worker_last_poly_max_degree, 
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
         master_max_remainder_degree,
-        num_polys, 
-        num_queries);
-    assert!(result.is_ok(), "{:}", result.err().unwrap()); 
+        num_polys,
+        num_queries,
+        None,
+        0);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
 }
 
 #[test]
@@ -49,14 +51,16 @@ fn test_fold_and_batch_multiple_poly() {
     let num_queries = 50;
 
     let result = fold_and_batch_prove_verify_random(
-        degree_bound_e, 
-        lde_blowup_e, 
-        folding_factor_e, 
-        worker_last_poly_max_degree, 
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
         master_max_remainder_degree,
-        num_polys, 
-        num_queries);
-    assert!(result.is_ok(), "{:}", result.err().unwrap()); 
+        num_polys,
+        num_queries,
+        None,
+        0);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
 }
 
 #[test]
@@ -70,14 +74,16 @@ fn test_fold_and_batch_master_complete_folding() {
     let num_queries = 50;
 
     let result = fold_and_batch_prove_verify_random(
-        degree_bound_e, 
-        lde_blowup_e, 
-        folding_factor_e, 
-        worker_last_poly_max_degree, 
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
         master_max_remainder_degree,
-        num_polys, 
-        num_queries);
-    assert!(result.is_ok(), "{:}", result.err().unwrap()); 
+        num_polys,
+        num_queries,
+        None,
+        0);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
 }
 
 
@@ -111,14 +117,131 @@ fn test_fold_and_batch_worker_folds_twice() {
     let num_queries = 50;
 
     let result = fold_and_batch_prove_verify_random(
-        degree_bound_e, 
-        lde_blowup_e, 
-        folding_factor_e, 
-        worker_last_poly_max_degree, 
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
+        master_max_remainder_degree,
+        num_polys,
+        num_queries,
+        None,
+        0);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fold_and_batch_zero_knowledge() {
+    let degree_bound_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let worker_last_poly_max_degree = 15;
+    let master_max_remainder_degree = 7;
+    let num_polys = 10;
+    let num_queries = 50;
+
+    let result = fold_and_batch_prove_verify_random(
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
+        master_max_remainder_degree,
+        num_polys,
+        num_queries,
+        Some(worker_last_poly_max_degree),
+        0);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fold_and_batch_erasure_coded_evaluations_do_not_affect_verification() {
+    let degree_bound_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let worker_last_poly_max_degree = 15;
+    let master_max_remainder_degree = 7;
+    let num_polys = 10;
+    let num_queries = 50;
+
+    let result = fold_and_batch_prove_verify_random(
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
+        master_max_remainder_degree,
+        num_polys,
+        num_queries,
+        None,
+        1);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fold_and_batch_with_grinding() {
+    let degree_bound_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let worker_last_poly_max_degree = 15;
+    let master_max_remainder_degree = 7;
+    let num_polys = 4;
+    let num_queries = 50;
+    let grinding_factor = 8;
+
+    let result = fold_and_batch_prove_verify_random_with_grinding(
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
+        master_max_remainder_degree,
+        num_polys,
+        num_queries,
+        grinding_factor);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fold_and_batch_tolerates_dropped_worker() {
+    let degree_bound_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let worker_last_poly_max_degree = 15;
+    let master_max_remainder_degree = 7;
+    let num_polys = 10;
+    let num_queries = 50;
+
+    // Drop the evaluations of worker 3; with a single parity vector attached, the verifier
+    // should reconstruct them and verify as if nothing were missing.
+    let result = fold_and_batch_prove_verify_random_with_dropped_worker(
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
+        master_max_remainder_degree,
+        num_polys,
+        num_queries,
+        1,
+        3);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fold_and_batch_with_interpolated_remainder() {
+    let degree_bound_e = 12;
+    let lde_blowup_e = 3;
+    let folding_factor_e = 2;
+    let worker_last_poly_max_degree = 15;
+    let master_max_remainder_degree = 7;
+    let num_polys = 10;
+    let num_queries = 50;
+
+    let result = fold_and_batch_prove_verify_random_with_interpolated_remainder(
+        degree_bound_e,
+        lde_blowup_e,
+        folding_factor_e,
+        worker_last_poly_max_degree,
         master_max_remainder_degree,
-        num_polys, 
+        num_polys,
         num_queries);
-    assert!(result.is_ok(), "{:}", result.err().unwrap()); 
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
 }
 
 
@@ -174,7 +297,7 @@ fn fold_and_batch_worker_prove(
     // Each worker node executes the FRI commit phase on their local input polynomial.
 
     // Instantiate a worker node.
-    let worker_options = FoldingOptions::new(lde_blowup, folding_factor, worker_domain_size, worker_last_poly_max_degree);
+    let worker_options = FoldingOptions::new(lde_blowup, folding_factor, worker_domain_size, worker_last_poly_max_degree).unwrap();
     let mut worker_node = FoldingProver::<BaseElement, DefaultProverChannel<_, _, _>, Blake3, MerkleTree<_>>::new(worker_options);
 
     // Prepare a ProverChannel for the worker node
@@ -186,7 +309,7 @@ fn fold_and_batch_worker_prove(
     // -------------------------- Step 3: worker query phase --------------------------------
     // Each worker node generates the FRI folding proof proving that the folding of its local 
     // polynomial was done correctly.
-    let (_, _) = worker_node.build_proof(&inputs, &query_positions);
+    let (_, _) = worker_node.build_proof(&inputs, &query_positions, 0);
 
     Ok(())
 }
@@ -198,9 +321,13 @@ fn fold_and_batch_worker_prove(
 /// 
 /// `num_polys` is the number of polynomials to be batched in batched FRI. It is equal to 
 /// the number of worker nodes.
-/// `worker_last_poly_max_degree` is the maximum degree of the polynomial in the last layer 
-/// of a worker node's FRI layers. In other words, each worker node will fold their local 
+/// `worker_last_poly_max_degree` is the maximum degree of the polynomial in the last layer
+/// of a worker node's FRI layers. In other words, each worker node will fold their local
 /// polynomial to a polynomial of degree <= `worker_last_poly_max_degree`.
+/// `blinding_degree`, when `Some`, enables zero-knowledge hiding (see
+/// [FoldingOptions::zk](super::FoldingOptions::zk)).
+/// `worker_evaluation_parity_count`, when greater than 0, enables Reed-Solomon erasure coding of
+/// the worker evaluations (see [encode_worker_evaluation_parity](super::encode_worker_evaluation_parity)).
 fn fold_and_batch_prove_verify_random(
     worker_degree_bound_e: usize,
     lde_blowup_e: usize,
@@ -208,13 +335,18 @@ fn fold_and_batch_prove_verify_random(
     worker_last_poly_max_degree: usize,
     master_remainder_max_degree: usize,
     num_poly: usize,
-    num_queries: usize
+    num_queries: usize,
+    blinding_degree: Option<usize>,
+    worker_evaluation_parity_count: usize,
 ) -> Result<(), VerifierError> {
 
     let worker_degree_bound = 1 << worker_degree_bound_e;
     let lde_blowup = 1 << lde_blowup_e;
     let folding_factor = 1 << folding_factor_e;
     let worker_domain_size = lde_blowup * worker_degree_bound;
+    let worker_degree_bounds = vec![worker_degree_bound; num_poly];
+    let worker_domain_sizes = vec![worker_domain_size; num_poly];
+    let worker_last_poly_max_degrees = vec![worker_last_poly_max_degree; num_poly];
     let master_degree_bound = worker_last_poly_max_degree + 1;
     let master_domain_size = lde_blowup * master_degree_bound.next_power_of_two();
     let master_options = FriOptions::new(lde_blowup, folding_factor, master_remainder_max_degree);
@@ -229,14 +361,17 @@ fn fold_and_batch_prove_verify_random(
 
     let fold_and_batch_proof = fold_and_batch_prove::<BaseElement, Blake3, DefaultRandomCoin<_>, MerkleTree<_>>(
         inputs,
-        num_poly, 
-        lde_blowup, 
-        folding_factor, 
-        worker_domain_size, 
-        worker_last_poly_max_degree, 
-        master_domain_size, 
+        num_poly,
+        lde_blowup,
+        folding_factor,
+        worker_domain_sizes,
+        worker_last_poly_max_degrees.clone(),
+        master_domain_size,
         master_options.clone(),
-        num_queries
+        num_queries,
+        blinding_degree,
+        worker_evaluation_parity_count,
+        false,
     );
 
 
@@ -249,10 +384,212 @@ fn fold_and_batch_prove_verify_random(
 
     // Instantiate the Fold-and-Batch verifier.
     let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
-    let mut verifier = FoldAndBatchVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, master_options, worker_degree_bound, master_degree_bound)?;
-    
+    let mut verifier = FoldAndBatchVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, master_options, worker_degree_bounds, worker_last_poly_max_degrees, master_degree_bound)?;
+
+    // Verify the Fold-and-Batch proof.
+    verifier.verify_fold_and_batch(&fold_and_batch_proof)?;
+
+    Ok(())
+}
+
+/// Same as [fold_and_batch_prove_verify_random], but additionally configures a non-zero
+/// grinding factor on the master's [FriOptions] so that the master's proof-of-work nonce
+/// search is exercised end-to-end, both when the prover grinds the query seed and when the
+/// verifier re-derives and checks it before sampling Fold-and-Batch query positions.
+fn fold_and_batch_prove_verify_random_with_grinding(
+    worker_degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    worker_last_poly_max_degree: usize,
+    master_remainder_max_degree: usize,
+    num_poly: usize,
+    num_queries: usize,
+    grinding_factor: u32,
+) -> Result<(), VerifierError> {
+
+    let worker_degree_bound = 1 << worker_degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let worker_domain_size = lde_blowup * worker_degree_bound;
+    let worker_degree_bounds = vec![worker_degree_bound; num_poly];
+    let worker_domain_sizes = vec![worker_domain_size; num_poly];
+    let worker_last_poly_max_degrees = vec![worker_last_poly_max_degree; num_poly];
+    let master_degree_bound = worker_last_poly_max_degree + 1;
+    let master_domain_size = lde_blowup * master_degree_bound.next_power_of_two();
+    let master_options = FriOptions::new(lde_blowup, folding_factor, master_remainder_max_degree)
+        .with_grinding_factor(grinding_factor);
+
+    assert!(worker_last_poly_max_degree >= master_remainder_max_degree, "The maximum degree for the worker node's last polynomial must be greater than or equal to the max remainder degree of the master node");
+
+    // Generate some random input evaluation vectors.
+    let mut inputs = Vec::with_capacity(num_poly);
+    for _ in 0..num_poly {
+        inputs.push(build_evaluations_from_random_poly(worker_degree_bound, lde_blowup));
+    }
+
+    let fold_and_batch_proof = fold_and_batch_prove::<BaseElement, Blake3, DefaultRandomCoin<_>, MerkleTree<_>>(
+        inputs,
+        num_poly,
+        lde_blowup,
+        folding_factor,
+        worker_domain_sizes,
+        worker_last_poly_max_degrees.clone(),
+        master_domain_size,
+        master_options.clone(),
+        num_queries,
+        None,
+        0,
+        false,
+    );
+
+    // Test proof serialization / deserialization.
+    let mut proof_bytes = Vec::new();
+    fold_and_batch_proof.write_into(&mut proof_bytes);
+
+    let mut reader = SliceReader::new(&proof_bytes);
+    let fold_and_batch_proof = FoldAndBatchProof::read_from(&mut reader).unwrap();
+
+    // Instantiate the Fold-and-Batch verifier.
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = FoldAndBatchVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, master_options, worker_degree_bounds, worker_last_poly_max_degrees, master_degree_bound)?;
+
     // Verify the Fold-and-Batch proof.
     verifier.verify_fold_and_batch(&fold_and_batch_proof)?;
-    
+
+    Ok(())
+}
+
+/// Like [fold_and_batch_prove_verify_random], but additionally simulates worker
+/// `dropped_worker_index`'s evaluations going missing in transit before verification, so the
+/// verifier can only succeed if it reconstructs them from `worker_evaluation_parity_count`
+/// Reed-Solomon parity vectors.
+fn fold_and_batch_prove_verify_random_with_dropped_worker(
+    worker_degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    worker_last_poly_max_degree: usize,
+    master_remainder_max_degree: usize,
+    num_poly: usize,
+    num_queries: usize,
+    worker_evaluation_parity_count: usize,
+    dropped_worker_index: usize,
+) -> Result<(), VerifierError> {
+
+    let worker_degree_bound = 1 << worker_degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let worker_domain_size = lde_blowup * worker_degree_bound;
+    let worker_degree_bounds = vec![worker_degree_bound; num_poly];
+    let worker_domain_sizes = vec![worker_domain_size; num_poly];
+    let worker_last_poly_max_degrees = vec![worker_last_poly_max_degree; num_poly];
+    let master_degree_bound = worker_last_poly_max_degree + 1;
+    let master_domain_size = lde_blowup * master_degree_bound.next_power_of_two();
+    let master_options = FriOptions::new(lde_blowup, folding_factor, master_remainder_max_degree);
+
+    assert!(worker_last_poly_max_degree >= master_remainder_max_degree, "The maximum degree for the worker node's last polynomial must be greater than or equal to the max remainder degree of the master node");
+
+    // Generate some random input evaluation vectors.
+    let mut inputs = Vec::with_capacity(num_poly);
+    for _ in 0..num_poly {
+        inputs.push(build_evaluations_from_random_poly(worker_degree_bound, lde_blowup));
+    }
+
+    let fold_and_batch_proof = fold_and_batch_prove::<BaseElement, Blake3, DefaultRandomCoin<_>, MerkleTree<_>>(
+        inputs,
+        num_poly,
+        lde_blowup,
+        folding_factor,
+        worker_domain_sizes,
+        worker_last_poly_max_degrees.clone(),
+        master_domain_size,
+        master_options.clone(),
+        num_queries,
+        None,
+        worker_evaluation_parity_count,
+        false,
+    );
+    let fold_and_batch_proof = fold_and_batch_proof.with_missing_worker_evaluation(dropped_worker_index);
+
+    // Test proof serialization / deserialization.
+    let mut proof_bytes = Vec::new();
+    fold_and_batch_proof.write_into(&mut proof_bytes);
+
+    let mut reader = SliceReader::new(&proof_bytes);
+    let fold_and_batch_proof = FoldAndBatchProof::read_from(&mut reader).unwrap();
+
+    // Instantiate the Fold-and-Batch verifier.
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = FoldAndBatchVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, master_options, worker_degree_bounds, worker_last_poly_max_degrees, master_degree_bound)?;
+
+    // Verify the Fold-and-Batch proof, reconstructing the dropped worker's evaluations from the
+    // attached parity vectors.
+    verifier.verify_fold_and_batch(&fold_and_batch_proof)?;
+
+    Ok(())
+}
+
+/// Same as [fold_and_batch_prove_verify_random], but additionally enables `interpolate_remainder`
+/// so that every worker attaches Lagrange-interpolated coefficients for its last layer (see
+/// [FoldingOptions::with_interpolated_remainder]), and the verifier checks those coefficients
+/// directly instead of opening a Merkle proof against the worker's function commitment.
+fn fold_and_batch_prove_verify_random_with_interpolated_remainder(
+    worker_degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    worker_last_poly_max_degree: usize,
+    master_remainder_max_degree: usize,
+    num_poly: usize,
+    num_queries: usize,
+) -> Result<(), VerifierError> {
+
+    let worker_degree_bound = 1 << worker_degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let worker_domain_size = lde_blowup * worker_degree_bound;
+    let worker_degree_bounds = vec![worker_degree_bound; num_poly];
+    let worker_domain_sizes = vec![worker_domain_size; num_poly];
+    let worker_last_poly_max_degrees = vec![worker_last_poly_max_degree; num_poly];
+    let master_degree_bound = worker_last_poly_max_degree + 1;
+    let master_domain_size = lde_blowup * master_degree_bound.next_power_of_two();
+    let master_options = FriOptions::new(lde_blowup, folding_factor, master_remainder_max_degree);
+
+    assert!(worker_last_poly_max_degree >= master_remainder_max_degree, "The maximum degree for the worker node's last polynomial must be greater than or equal to the max remainder degree of the master node");
+
+    // Generate some random input evaluation vectors.
+    let mut inputs = Vec::with_capacity(num_poly);
+    for _ in 0..num_poly {
+        inputs.push(build_evaluations_from_random_poly(worker_degree_bound, lde_blowup));
+    }
+
+    let fold_and_batch_proof = fold_and_batch_prove::<BaseElement, Blake3, DefaultRandomCoin<_>, MerkleTree<_>>(
+        inputs,
+        num_poly,
+        lde_blowup,
+        folding_factor,
+        worker_domain_sizes,
+        worker_last_poly_max_degrees.clone(),
+        master_domain_size,
+        master_options.clone(),
+        num_queries,
+        None,
+        0,
+        true,
+    );
+
+    // Test proof serialization / deserialization.
+    let mut proof_bytes = Vec::new();
+    fold_and_batch_proof.write_into(&mut proof_bytes);
+
+    let mut reader = SliceReader::new(&proof_bytes);
+    let fold_and_batch_proof = FoldAndBatchProof::read_from(&mut reader).unwrap();
+
+    // Instantiate the Fold-and-Batch verifier.
+    let public_coin = DefaultRandomCoin::<Blake3>::new(&[]);
+    let mut verifier = FoldAndBatchVerifier::<BaseElement, DefaultVerifierChannel<BaseElement, _, MerkleTree<Blake3>>, _, DefaultRandomCoin<_>, _>::new(public_coin, num_queries, master_options, worker_degree_bounds, worker_last_poly_max_degrees, master_degree_bound)?;
+
+    // Verify the Fold-and-Batch proof, checking every worker's interpolated remainder
+    // coefficients directly instead of opening a Merkle proof against its function commitment.
+    verifier.verify_fold_and_batch(&fold_and_batch_proof)?;
+
     Ok(())
 }
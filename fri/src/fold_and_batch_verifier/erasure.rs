@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use math::FieldElement;
+
+use crate::fold_and_batch_prover::canonical_points;
+use crate::fold_and_batch_verifier::lagrange_interpolate_eval;
+use crate::VerifierError;
+
+// WORKER EVALUATION ERASURE CODING (DECODE)
+// ================================================================================================
+//
+// See [encode_worker_evaluation_parity](crate::fold_and_batch_prover::encode_worker_evaluation_parity)
+// for how the `parity_evaluations`/`parity_points` consumed here were produced. Every worker
+// vector and every parity vector is, position-by-position, an evaluation of the same degree `<
+// n` polynomial at that vector's canonical point; as long as at least `n` of the `n +
+// parity_evaluations.len()` vectors survive, any missing worker vector can be recovered
+// independently per position by Lagrange-interpolating through `n` of the surviving (point,
+// symbol) pairs and evaluating the result back at the missing worker's own point.
+
+/// Rebuilds every `None` entry of `worker_evaluations` using the surviving worker vectors and
+/// `parity_evaluations`/`parity_points`, and returns the full, gap-free set of worker evaluation
+/// vectors.
+///
+/// Entries that are already `Some` are returned unchanged; `reconstruct_worker_evaluations` is a
+/// no-op if none are missing.
+///
+/// Returns [VerifierError::InvalidPolynomialBatching] if the worker and parity points used for a
+/// reconstruction are not pairwise distinct (see [lagrange_interpolate_eval]) -- a prover could
+/// otherwise supply colliding parity points to make the interpolation degenerate.
+///
+/// # Panics
+/// Panics if fewer than `worker_evaluations.len()` of the surviving worker and parity vectors are
+/// available to reconstruct from.
+pub(crate) fn reconstruct_worker_evaluations<E: FieldElement>(
+    worker_evaluations: &[Option<Vec<E>>],
+    parity_evaluations: &[Vec<E>],
+    parity_points: &[E],
+) -> Result<Vec<Vec<E>>, VerifierError> {
+    let n = worker_evaluations.len();
+    let surviving_workers = worker_evaluations.iter().filter(|w| w.is_some()).count();
+    assert!(
+        surviving_workers + parity_evaluations.len() >= n,
+        "not enough surviving worker and parity vectors to reconstruct the missing worker evaluations"
+    );
+
+    let missing: Vec<usize> = worker_evaluations
+        .iter()
+        .enumerate()
+        .filter_map(|(i, w)| if w.is_none() { Some(i) } else { None })
+        .collect();
+
+    let mut reconstructed: Vec<Vec<E>> = worker_evaluations
+        .iter()
+        .map(|w| w.clone().unwrap_or_default())
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(reconstructed);
+    }
+
+    let position_count = worker_evaluations
+        .iter()
+        .flatten()
+        .map(|w| w.len())
+        .chain(parity_evaluations.iter().map(|w| w.len()))
+        .next()
+        .expect("at least one surviving worker or parity vector is required");
+
+    let worker_points = canonical_points::<E>(0, n);
+
+    for j in 0..position_count {
+        let mut xs = Vec::with_capacity(n);
+        let mut ys = Vec::with_capacity(n);
+        for (i, w) in worker_evaluations.iter().enumerate() {
+            if let Some(values) = w {
+                xs.push(worker_points[i]);
+                ys.push(values[j]);
+            }
+        }
+        for (&point, parity_vector) in parity_points.iter().zip(parity_evaluations.iter()) {
+            if xs.len() == n {
+                break;
+            }
+            xs.push(point);
+            ys.push(parity_vector[j]);
+        }
+
+        for &i in missing.iter() {
+            reconstructed[i].push(lagrange_interpolate_eval(&xs, &ys, worker_points[i])?);
+        }
+    }
+
+    Ok(reconstructed)
+}
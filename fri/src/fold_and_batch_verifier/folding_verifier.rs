@@ -0,0 +1,251 @@
+use core::marker::PhantomData;
+
+use alloc::{format, vec::Vec};
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use math::{FieldElement, StarkField};
+use utils::group_slice_elements;
+
+use crate::fold_and_batch_prover::FoldingOptions;
+use crate::folding::fold_positions;
+use crate::{VerifierChannel, VerifierError};
+
+// FOLDING VERIFIER
+// ================================================================================================
+
+/// Verifies the [FoldingProof](crate::fold_and_batch_proof::FoldingProof) a single worker node
+/// produces for [FoldingProver](crate::FoldingProver), i.e. the worker-side half of the
+/// Fold-and-Batch protocol.
+///
+/// This mirrors [FriVerifier](crate::FriVerifier) closely, with the same difference from
+/// [FoldingProver](crate::FoldingProver) as on the prover side: a worker never commits to its own
+/// last layer (the master commits it instead, one function commitment per worker), so there is no
+/// remainder to check here. That every worker's last layer matches the master's commitment to it,
+/// and the resulting batched polynomial's own low-degree proof, are instead checked by
+/// [FoldAndBatchVerifier::verify_batched_fri](crate::fold_and_batch_verifier::FoldAndBatchVerifier::verify_batched_fri).
+pub(crate) struct FoldingVerifier<E, C, H, R, V>
+where
+    E: FieldElement,
+    C: VerifierChannel<E, Hasher = H, VectorCommitment = V>,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    domain_size: usize,
+    domain_offset: E::BaseField,
+    folding_schedule: Vec<u8>,
+    layer_commitments: Vec<H::Digest>,
+    layer_alphas: Vec<E>,
+    _channel: PhantomData<C>,
+    _public_coin: PhantomData<R>,
+}
+
+impl<E, C, H, R, V> FoldingVerifier<E, C, H, R, V>
+where
+    E: FieldElement,
+    C: VerifierChannel<E, Hasher = H, VectorCommitment = V>,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+    V: VectorCommitment<H>,
+{
+    /// Returns a new [FoldingVerifier] for a worker configured with `options`, reading that
+    /// worker's layer commitments off `channel` and re-deriving the folding randomness for every
+    /// layer from `public_coin`, exactly as [FriVerifier::new](crate::FriVerifier::new) does for
+    /// the standalone FRI protocol.
+    ///
+    /// `max_poly_degree` is the maximum degree of the polynomial this worker started folding
+    /// from, i.e. one less than its own degree bound.
+    ///
+    /// `folding_schedule` is the folding arity claimed for each of this worker's layers, taken
+    /// from its [FoldingProof](crate::fold_and_batch_proof::FoldingProof); it must fold
+    /// `options.domain_size()` down to exactly `options.final_domain_size()`.
+    ///
+    /// # Errors
+    /// Returns an error if `folding_schedule`'s arities do not multiply out to exactly
+    /// `options.final_domain_size()` starting from `options.domain_size()`.
+    pub fn new(
+        channel: &mut C,
+        public_coin: &mut R,
+        options: FoldingOptions,
+        max_poly_degree: usize,
+        folding_schedule: Vec<u8>,
+    ) -> Result<Self, VerifierError> {
+        let _ = max_poly_degree;
+
+        let mut domain_size = options.domain_size();
+        for &arity in folding_schedule.iter() {
+            let arity = arity as usize;
+            if arity == 0 || domain_size % arity != 0 {
+                return Err(VerifierError::InvalidValueInEvaluationsVector(format!(
+                    "folding schedule arity {arity} does not evenly divide the domain size {domain_size} it is applied to"
+                )));
+            }
+            domain_size /= arity;
+        }
+        if domain_size != options.final_domain_size() {
+            return Err(VerifierError::InvalidValueInEvaluationsVector(format!(
+                "folding schedule folds the domain down to {domain_size}, but the master batches in this worker's last layer at domain size {}",
+                options.final_domain_size()
+            )));
+        }
+
+        let layer_commitments = channel.read_fri_layer_commitments();
+        assert_eq!(
+            layer_commitments.len(),
+            folding_schedule.len(),
+            "the folding schedule must declare exactly one arity per committed layer"
+        );
+
+        // Re-derive the folding randomness used at every layer from the commitments, in the order
+        // they were committed, so the verifier never has to trust a prover-supplied alpha.
+        let mut layer_alphas = Vec::with_capacity(layer_commitments.len());
+        for &commitment in layer_commitments.iter() {
+            public_coin.reseed(commitment);
+            let alpha: E = public_coin
+                .draw()
+                .expect("failed to draw a folding verifier layer alpha");
+            layer_alphas.push(alpha);
+        }
+
+        Ok(FoldingVerifier {
+            domain_size: options.domain_size(),
+            domain_offset: options.domain_offset(),
+            folding_schedule,
+            layer_commitments,
+            layer_alphas,
+            _channel: PhantomData,
+            _public_coin: PhantomData,
+        })
+    }
+
+    /// Replays this worker's folding proof: for every layer, the queried rows are checked against
+    /// the commitment read in [new](Self::new), the values claimed by the previous layer (at
+    /// depth 0, `evaluations`, i.e. the worker's own opened input evaluations) are checked to
+    /// actually appear in those rows, and the rows are folded down via degree-respecting
+    /// projection to the values the next layer must claim.
+    ///
+    /// `evaluations` are the worker's own local polynomial evaluated at `positions`, i.e. the
+    /// `evaluation_vector` returned alongside a
+    /// [FoldingProof](crate::fold_and_batch_proof::FoldingProof) by
+    /// [FoldingProver::build_proof](crate::FoldingProver::build_proof). `positions` are the query
+    /// positions over this worker's own domain (already folded down from the shared
+    /// Fold-and-Batch sampling domain by the caller).
+    ///
+    /// Returns the values the last layer folded down to, in the same order as the fully-folded
+    /// positions, since a worker never commits to its own last layer: most callers have nothing
+    /// of their own to check them against and simply discard them, but a caller that does commit
+    /// to that last layer itself (as [FoldingPcs](crate::folding_pcs::FoldingPcs) does for the
+    /// quotient it proves low-degree) can authenticate them against that commitment.
+    ///
+    /// # Panics
+    /// Panics if `evaluations` and `positions` do not have the same length.
+    pub fn verify(
+        &mut self,
+        channel: &mut C,
+        evaluations: &[E],
+        positions: &[usize],
+    ) -> Result<Vec<E>, VerifierError> {
+        assert_eq!(
+            evaluations.len(),
+            positions.len(),
+            "one evaluation must be supplied per queried position"
+        );
+
+        let mut domain_size = self.domain_size;
+        let mut prev_positions = positions.to_vec();
+        let mut prev_values = evaluations.to_vec();
+
+        for depth in 0..self.layer_commitments.len() {
+            let folding_factor = self.folding_schedule[depth] as usize;
+            let folded_positions = fold_positions(&prev_positions, domain_size, folding_factor);
+
+            prev_values = match folding_factor {
+                2 => self.verify_layer::<2>(channel, depth, domain_size, &prev_positions, &prev_values, &folded_positions)?,
+                4 => self.verify_layer::<4>(channel, depth, domain_size, &prev_positions, &prev_values, &folded_positions)?,
+                8 => self.verify_layer::<8>(channel, depth, domain_size, &prev_positions, &prev_values, &folded_positions)?,
+                16 => self.verify_layer::<16>(channel, depth, domain_size, &prev_positions, &prev_values, &folded_positions)?,
+                _ => unimplemented!("folding factor {} is not supported", folding_factor),
+            };
+
+            prev_positions = folded_positions;
+            domain_size /= folding_factor;
+        }
+
+        Ok(prev_values)
+    }
+
+    /// Verifies a single layer: reads the queried rows and their opening proof off `channel` and
+    /// checks them against `self.layer_commitments[depth]`, checks that `prev_values` (claimed by
+    /// the previous layer at `prev_positions`) actually appear in those rows, and folds every
+    /// opened row down via degree-respecting projection.
+    ///
+    /// Returns the folded values, in the same order as `folded_positions`, for the next layer (or
+    /// the caller, at the last layer) to check in turn.
+    fn verify_layer<const N: usize>(
+        &self,
+        channel: &mut C,
+        depth: usize,
+        domain_size: usize,
+        prev_positions: &[usize],
+        prev_values: &[E],
+        folded_positions: &[usize],
+    ) -> Result<Vec<E>, VerifierError> {
+        let layer_values = channel.take_next_fri_layer_queries();
+        let query_rows: &[[E; N]] = group_slice_elements(&layer_values);
+
+        let hashed_values: Vec<H::Digest> = query_rows.iter().map(|row| H::hash_elements(row)).collect();
+        let opening_proof = channel.take_next_fri_layer_proof();
+        V::verify_many(self.layer_commitments[depth], folded_positions, &hashed_values, &opening_proof)
+            .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
+
+        // The values the previous layer (or, at depth 0, the worker's own opened evaluations)
+        // claimed must actually show up in the rows just verified, at the slot their pre-fold
+        // position maps to.
+        let folded_domain_size = domain_size / N;
+        for (&position, &expected) in prev_positions.iter().zip(prev_values.iter()) {
+            let folded_position = position % folded_domain_size;
+            let row_index = folded_positions
+                .iter()
+                .position(|&p| p == folded_position)
+                .expect("a folded position claimed by the previous layer was not opened at this layer");
+            let slot = position / folded_domain_size;
+            if query_rows[row_index][slot] != expected {
+                return Err(VerifierError::LayerCommitmentMismatch);
+            }
+        }
+
+        // Fold every opened row down via degree-respecting projection to obtain the values the
+        // next layer (or the caller, at the last layer) must claim at `folded_positions`.
+        let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+        let folded_values = folded_positions
+            .iter()
+            .zip(query_rows.iter())
+            .map(|(&position, row)| {
+                let x = self.domain_offset * g.exp((position as u64).into());
+                fold_row(row, x, self.layer_alphas[depth])
+            })
+            .collect();
+
+        Ok(folded_values)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the evaluation, at `alpha`, of the unique polynomial of degree less than `N`
+/// interpolating `row` over the coset `{x, x * g, x * g^2, ..., x * g^(N - 1)}`, where `g` is a
+/// primitive `N`-th root of unity.
+///
+/// This is the per-row computation that [apply_drp](crate::folding::apply_drp) performs across an
+/// entire layer at once on the prover side; verification only ever has the rows the prover
+/// actually opened, so each opened row is folded individually against the query position it came
+/// from.
+fn fold_row<E: FieldElement, const N: usize>(row: &[E; N], x: E::BaseField, alpha: E) -> E {
+    let g = E::BaseField::get_root_of_unity(N.ilog2());
+    let xs: Vec<E> = (0..N as u64)
+        .map(|k| E::from(x * g.exp(k.into())))
+        .collect();
+
+    let coefficients = math::polynom::interpolate(&xs, row, true);
+    math::polynom::eval(&coefficients, alpha)
+}
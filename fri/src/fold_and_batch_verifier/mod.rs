@@ -1,18 +1,30 @@
 use core::marker::PhantomData;
 
 use alloc::string::ToString;
-use alloc::vec::Vec;
+use alloc::{format, vec, vec::Vec};
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use math::FieldElement;
+use math::{FieldElement, StarkField};
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+
 use utils::group_slice_elements;
 
+use crate::batched_prover::combine_poly_evaluations;
+use crate::batched_verifier::extract_evaluations;
 use crate::fold_and_batch_prover::FoldingOptions;
 use crate::folding::fold_positions;
-use crate::{BatchedFriProof, DefaultVerifierChannel, FoldAndBatchProof, FoldingVerifierChannel, FriOptions, FriProofLayer, FriVerifier, VerifierChannel, VerifierError, batched_verifier::verify_batching};
+use crate::transcript::verify_grinding;
+use crate::{BatchedFriProof, DefaultVerifierChannel, FoldAndBatchProof, FoldingVerifierChannel, FriOptions, FriProofLayer, FriVerifier, VerifierChannel, VerifierError};
 
 mod folding_verifier;
 pub(crate) use folding_verifier::FoldingVerifier;
 
+mod remainder;
+pub use remainder::lagrange_interpolate_eval;
+
+mod erasure;
+pub(crate) use erasure::reconstruct_worker_evaluations;
+
 pub struct FoldAndBatchVerifier<E, C, H, R, V>
 where
     E: FieldElement,
@@ -22,9 +34,11 @@ where
     V: VectorCommitment<H>,
 {
     public_coin: R,
-    worker_degree_bound: usize,
+    worker_degree_bounds: Vec<usize>,
     master_degree_bound: usize,
-    worker_domain_size: usize,
+    worker_domain_sizes: Vec<usize>,
+    worker_last_poly_max_degrees: Vec<usize>,
+    worker_function_domain_sizes: Vec<usize>,
     master_domain_size: usize,
     num_queries: usize,
     options: FriOptions,
@@ -41,20 +55,62 @@ where
     R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
 {
+    /// Returns a new [FoldAndBatchVerifier].
+    ///
+    /// `worker_degree_bounds` holds one degree bound per worker node, in the same order the
+    /// worker nodes appear in the [FoldAndBatchProof] this verifier will check, so that workers
+    /// holding traces of differing lengths can be aggregated into a single proof, much like
+    /// plonky2's `FriBatchInfo`/`FriInstanceInfo` batch polynomials of differing degrees into one
+    /// FRI instance.
+    ///
+    /// `worker_last_poly_max_degrees` holds, for each worker in the same order, the degree bound
+    /// its local folding stops at (see
+    /// [fold_and_batch_worker_commit](crate::fold_and_batch_prover::fold_and_batch_worker_commit)),
+    /// so workers may hand their last layer to the master at differing domain sizes rather than
+    /// all landing on `master_degree_bound`'s domain.
+    ///
+    /// # Panics
+    /// Panics if `worker_degree_bounds` is empty, if `worker_last_poly_max_degrees.len()` does
+    /// not equal `worker_degree_bounds.len()`, or if some worker's own degree bound is not
+    /// strictly greater than the degree bound its last layer folds down to.
     pub fn new(
         public_coin: R,
         num_queries: usize,
         options: FriOptions,
-        worker_degree_bound: usize,
+        worker_degree_bounds: Vec<usize>,
+        worker_last_poly_max_degrees: Vec<usize>,
         master_degree_bound: usize,
     ) -> Result<Self, VerifierError> {
-        assert!(worker_degree_bound >= master_degree_bound, "The degree bound for worker nodes must be greater than or equal to the degree bound for the master node");
-        
+        assert!(!worker_degree_bounds.is_empty(), "at least one worker node is required");
+        assert_eq!(
+            worker_degree_bounds.len(),
+            worker_last_poly_max_degrees.len(),
+            "an ending degree bound must be provided for every worker node"
+        );
+        assert!(
+            worker_degree_bounds
+                .iter()
+                .zip(worker_last_poly_max_degrees.iter())
+                .all(|(&bound, &last_poly_max_degree)| bound > last_poly_max_degree),
+            "every worker node's own degree bound must be strictly greater than the degree bound its last layer folds down to"
+        );
+
+        let worker_domain_sizes = worker_degree_bounds
+            .iter()
+            .map(|&bound| options.blowup_factor() * bound.next_power_of_two())
+            .collect();
+        let worker_function_domain_sizes = worker_last_poly_max_degrees
+            .iter()
+            .map(|&degree| options.blowup_factor() * (degree + 1).next_power_of_two())
+            .collect();
+
         Ok(FoldAndBatchVerifier {
             public_coin,
-            worker_degree_bound,
+            worker_degree_bounds,
             master_degree_bound,
-            worker_domain_size: options.blowup_factor() * worker_degree_bound.next_power_of_two(),
+            worker_domain_sizes,
+            worker_last_poly_max_degrees,
+            worker_function_domain_sizes,
             master_domain_size: options.blowup_factor() * master_degree_bound.next_power_of_two(),
             num_queries,
             options,
@@ -69,24 +125,98 @@ where
         self.options.folding_factor()
     }
 
-    /// Return the number of times the worker nodes fold their local polynomials. This
-    /// number is determined by the ratio worker_domain_size / master_domain_size and the
-    /// folding_factor.
-    fn num_worker_folding(&self) -> usize {
+    /// Returns the largest domain size among all worker nodes.
+    ///
+    /// Fold-and-Batch query positions are sampled once over this domain, since it is a superset
+    /// of every (possibly smaller) worker's own domain; a worker whose own domain is smaller then
+    /// folds those positions down to its own domain via [fold_query_positions](Self::fold_query_positions).
+    fn max_worker_domain_size(&self) -> usize {
+        *self.worker_domain_sizes.iter().max().expect("at least one worker node is required")
+    }
+
+    /// Checks that a master remainder sent as evaluations over a small canonical point set
+    /// (rather than as explicit coefficients) takes the expected values at the given points.
+    ///
+    /// `remainder_points`/`remainder_evaluations` are the canonical point set and the prover's
+    /// claimed evaluations of the remainder polynomial there; `final_points`/`final_values` are
+    /// points and the values the remainder must take at them. [verify_fold_and_batch](Self::verify_fold_and_batch)
+    /// passes a single point drawn from the public coin and the value there of the remainder
+    /// coefficients embedded in the FRI proof. The remainder is evaluated from the claimed
+    /// evaluations via [lagrange_interpolate_eval] at every final point to confirm agreement,
+    /// instead of trusting the transmitted evaluations outright.
+    fn verify_remainder_by_interpolation(
+        &self,
+        remainder_points: &[E],
+        remainder_evaluations: &[E],
+        final_points: &[E],
+        final_values: &[E],
+    ) -> Result<(), VerifierError> {
+        assert_eq!(final_points.len(), final_values.len(), "final points and values must be the same length");
+
+        for (&point, &expected_value) in final_points.iter().zip(final_values.iter()) {
+            let reconstructed_value = lagrange_interpolate_eval(remainder_points, remainder_evaluations, point)?;
+            if reconstructed_value != expected_value {
+                return Err(VerifierError::InvalidPolynomialBatching);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of times a value sampled over a domain of size `from_domain_size` must
+    /// be folded by the folding factor to land on a domain of size `to_domain_size`.
+    ///
+    /// Before heterogeneous worker degree bounds, every worker folded the same number of times
+    /// to reach `master_domain_size` from one shared `worker_domain_size`; now each worker's own
+    /// domain size yields its own fold count, so both ends are taken as parameters instead of
+    /// always folding from a single `self` field down to `master_domain_size`.
+    fn num_worker_folding(&self, from_domain_size: usize, to_domain_size: usize) -> usize {
         let mut result = 0;
-        let mut current_domain_size = self.worker_domain_size;
-        while current_domain_size > self.master_domain_size {
+        let mut current_domain_size = from_domain_size;
+        while current_domain_size > to_domain_size {
             current_domain_size /= self.folding_factor();
             result += 1;
         }
         result
     }
 
-    /// This method is used to verify the batched FRI proof in a FoldAndBatchedProof. The verification 
-    /// procedure is different from the verification of a standalone batched FRI proof in that the 
+    /// Folds `positions`, sampled over a domain of size `from_domain_size`, down to a domain of
+    /// size `to_domain_size`.
+    fn fold_query_positions(&self, positions: &[usize], from_domain_size: usize, to_domain_size: usize) -> Vec<usize> {
+        let mut positions = positions.to_vec();
+        let mut current_domain_size = from_domain_size;
+        for _ in 0..self.num_worker_folding(from_domain_size, to_domain_size) {
+            positions = fold_positions(&positions, current_domain_size, self.folding_factor());
+            current_domain_size /= self.folding_factor();
+        }
+        positions
+    }
+
+    /// This method is used to verify the batched FRI proof in a FoldAndBatchedProof. The verification
+    /// procedure is different from the verification of a standalone batched FRI proof in that the
     /// verifier must first read all the layer commitments from the worker nodes before deriving the
     /// batched FRI challenge.
-    pub fn verify_batched_fri(&mut self, proof: BatchedFriProof<H>, worker_layer_commitments: Vec<Vec<H::Digest>>) -> Result<Vec<usize>, VerifierError> {
+    ///
+    /// `worker_remainder_coefficients` holds, for each worker in the same order, the coefficients
+    /// attached via [FoldAndBatchProof::with_worker_remainder_coefficients](crate::FoldAndBatchProof::with_worker_remainder_coefficients),
+    /// or an empty entry for a worker that was not configured with `interpolate_remainder`. A
+    /// worker with a non-empty entry has its last layer checked directly by re-evaluating those
+    /// coefficients at the queried positions (see [verify_interpolated_remainder](Self::verify_interpolated_remainder))
+    /// instead of opening a Merkle proof against its function commitment.
+    pub fn verify_batched_fri(
+        &mut self,
+        proof: BatchedFriProof<H>,
+        worker_layer_commitments: Vec<Vec<H::Digest>>,
+        blinding_evaluations: Option<Vec<E>>,
+        worker_remainder_coefficients: Vec<Vec<E>>,
+    ) -> Result<Vec<usize>, VerifierError> {
+
+        let num_worker = worker_layer_commitments.len();
+        assert_eq!(
+            worker_remainder_coefficients.len(),
+            num_worker,
+            "a remainder coefficients entry (possibly empty) must be present for every worker"
+        );
 
         // Read the worker layer commitments and reseed the random coin.
         for commitments_vec in worker_layer_commitments {
@@ -114,11 +244,27 @@ where
         )?;
 
 
+        // If the prover performed proof-of-work grinding, reseed the coin with the claimed nonce
+        // and reject the proof unless it actually satisfies the leading-zero requirement. This
+        // must happen before the query positions are sampled, since the nonce is what binds the
+        // grinding work to the positions that get drawn next.
+        let grinding_factor = self.options.grinding_factor();
+        let pow_nonce = proof.pow_nonce();
+        if !verify_grinding(&mut self.public_coin, grinding_factor, pow_nonce) {
+            return Err(VerifierError::ProofOfWorkVerificationFailed);
+        }
+
         // Sample the query positions using Fiat-Shamir. Since these are the query positions
-        // used for Fold-and-Batch, we draw the queries from the range [0, worker_domain_size). 
-        // TODO: consider using grinding?
+        // used for Fold-and-Batch, we draw the queries from the range [0, sampling_domain_size),
+        // where sampling_domain_size is the largest domain among all worker nodes: every other
+        // (smaller) worker's positions are recovered by folding these down further below.
+        // The coin was already reseeded with `pow_nonce` by `verify_grinding` above, so draw
+        // with a nonce of 0 here -- passing `pow_nonce` again would reseed a second time and
+        // desync from the prover, which only ever reseeds with it once (see
+        // `BatchedFriProverChannel::grind_query_seed`).
+        let sampling_domain_size = self.max_worker_domain_size();
         let mut query_positions = self.public_coin
-            .draw_integers(self.num_queries, self.worker_domain_size, 0)
+            .draw_integers(self.num_queries, sampling_domain_size, 0)
             .expect("Failed to draw Fold-and-Batch query positions");
 
         // Remove any potential duplicates from the positions as the prover will send openings only
@@ -126,83 +272,154 @@ where
         query_positions.sort_unstable();
         query_positions.dedup();
 
-        // Record the query positions used by the worker nodes for the verification of folding 
+        // Record the query positions used by the worker nodes for the verification of folding
         // proofs later.
         let worker_query_positions = query_positions.to_vec();
 
-        // Fold the query positions for Fold-and-Batch N times where N is how many times the worker 
-        // nodes fold their local polynomials. This is to obtain the query positions for batched FRI.
-        let mut current_domain_size = self.worker_domain_size;
-        for _ in 0..self.num_worker_folding() {
-            query_positions = fold_positions(&query_positions, current_domain_size, self.folding_factor());
-            current_domain_size /= self.folding_factor();
-        }
+        // Fold the query positions down to the master domain, where every worker's last layer
+        // lands regardless of its own starting degree bound, to obtain the query positions for
+        // batched FRI.
+        query_positions = self.fold_query_positions(&query_positions, sampling_domain_size, self.master_domain_size);
 
         // Read the evaluations of the batched polynomial at the query positions.
         let queried_evaluations = proof.parse_evaluations()?;
 
         // Verify the FRI proof.
-        fri_verifier.verify(&mut fri_verifier_channel, &queried_evaluations, &query_positions)?; 
-
-        let batching_proofs = proof.batching_proofs().to_vec();
-        let folding_factor = self.folding_factor();
-        let (queried_values, opening_proofs) = self.parse_batching_proofs(batching_proofs)?;
+        fri_verifier.verify(&mut fri_verifier_channel, &queried_evaluations, &query_positions)?;
 
-        // Verify that the opening proofs for the worker witness polynomials are valid against their commitments.
+        // Verify every worker's own opening proof for its last-layer evaluations, each against its
+        // own function commitment rather than one shared combined tree, since workers may now end
+        // their local folding at differing domain sizes. Each worker's evaluations are recovered at
+        // `worker_query_positions` (the shared Fold-and-Batch sampling positions, folded down to
+        // that worker's own function domain size) for the batching check below.
         let function_commitments = proof.function_commitments();
-        match folding_factor {
-            2 => self.verify_opening_proofs::<2>(function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            4 => self.verify_opening_proofs::<4>(function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            8 => self.verify_opening_proofs::<8>(function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            16 => self.verify_opening_proofs::<16>(function_commitments, &queried_values, &opening_proofs, &query_positions)?,
-            _ => unimplemented!("folding factor {} is not supported", folding_factor),
+        let function_openings = proof.batching_proofs();
+        assert_eq!(
+            function_commitments.len(),
+            function_openings.len(),
+            "every function commitment must have a matching opening"
+        );
+        assert_eq!(
+            function_commitments.len(),
+            num_worker,
+            "a function commitment must be present for every worker"
+        );
+
+        let verify_one = |i: usize| -> Result<Vec<E>, VerifierError> {
+            let domain_size = self.worker_function_domain_sizes[i];
+            let positions = self.fold_query_positions(&worker_query_positions, sampling_domain_size, domain_size);
+            if worker_remainder_coefficients[i].is_empty() {
+                self.verify_function_opening(function_commitments[i], &function_openings[i], domain_size, &positions)
+            } else {
+                self.verify_interpolated_remainder(&worker_remainder_coefficients[i], i, domain_size, &positions)
+            }
+        };
+
+        #[cfg(feature = "concurrent")]
+        let results: Vec<Result<Vec<E>, VerifierError>> = (0..num_worker).into_par_iter().map(verify_one).collect();
+        #[cfg(not(feature = "concurrent"))]
+        let results: Vec<Result<Vec<E>, VerifierError>> = (0..num_worker).map(verify_one).collect();
+        let queried_values: Vec<Vec<E>> = results.into_iter().collect::<Result<_, _>>()?;
+
+        // A zero-knowledge prover bakes a combined blinding contribution into `queried_evaluations`
+        // (see [FoldingOptions::zk](crate::fold_and_batch_prover::FoldingOptions::zk)); cancel it
+        // out before checking the random linear combination. A non-ZK proof carries no blinding
+        // evaluations, in which case the check proceeds exactly as before.
+        //
+        // Unlike [verify_batching](crate::batched_verifier::verify_batching), `queried_values` here
+        // is already one evaluation per worker per position in `worker_query_positions` (each
+        // worker's opening was just verified at its own domain size above), so the random linear
+        // combination is checked directly rather than re-extracting through a shared domain size.
+        let expected_evaluations = combine_poly_evaluations(&queried_values, batched_fri_challenge);
+        let matches = match blinding_evaluations {
+            Some(blinding_evaluations) => {
+                assert_eq!(
+                    blinding_evaluations.len(),
+                    queried_evaluations.len(),
+                    "one blinding evaluation must be supplied per queried batched evaluation"
+                );
+                expected_evaluations
+                    .iter()
+                    .zip(queried_evaluations.iter())
+                    .zip(blinding_evaluations.iter())
+                    .all(|((expected, &actual), &blinding)| *expected == actual - blinding)
+            }
+            None => expected_evaluations == queried_evaluations,
+        };
+        if !matches {
+            return Err(VerifierError::InvalidPolynomialBatching);
         }
-        
-        // Verify that the random linear combination using batched fri challenge was computed correctly.
-        verify_batching(
-            &query_positions, 
-            &queried_evaluations, 
-            &queried_values, 
-            batched_fri_challenge, 
-            self.master_domain_size, 
-            folding_factor)?;
-            
+
         Ok(worker_query_positions)
     }
 
 
-    pub fn verify_fold_and_batch(&mut self, proof: &FoldAndBatchProof<E, H>) -> Result<(), VerifierError> {
-        
+    /// Verifies a [FoldAndBatchProof] end-to-end, checking all three of the properties a
+    /// Fold-and-Batch proof needs to actually prove:
+    ///
+    /// 1. Every worker's folding proof is internally consistent: each
+    ///    [FoldingProof](crate::fold_and_batch_proof::FoldingProof)'s layers are replayed by a
+    ///    [FoldingVerifier] (below), checking every layer's Merkle openings against
+    ///    `worker_layer_commitments` and the degree-respecting-projection consistency between
+    ///    consecutive layers.
+    /// 2. Every worker's last (uncommitted) layer matches the master's function commitment to that
+    ///    worker's layer (one commitment per worker), checked by [verify_function_opening](Self::verify_function_opening)
+    ///    or [verify_interpolated_remainder](Self::verify_interpolated_remainder) and the batching
+    ///    check in [verify_batched_fri](Self::verify_batched_fri).
+    /// 3. The resulting batched polynomial itself satisfies the standard FRI low-degree check,
+    ///    also run by [verify_batched_fri](Self::verify_batched_fri).
+    ///
+    /// All Fiat-Shamir challenges (the per-worker folding alphas, and the master's batching
+    /// challenge) are re-derived from the commitments in the order they were serialized, rather
+    /// than trusted from the proof.
+    pub fn verify_fold_and_batch(&mut self, proof: &FoldAndBatchProof<H>) -> Result<(), VerifierError> {
+
         // ------------------- Step 1: Prepare the folding verifiers ----------------------------------------
         
         let folding_proofs = proof.folding_proofs();
         let num_worker = folding_proofs.len();
+        assert_eq!(
+            num_worker,
+            self.worker_degree_bounds.len(),
+            "the number of folding proofs must match the number of worker degree bounds this verifier was configured with"
+        );
 
         let mut folding_verifiers : Vec<FoldingVerifier<E, FoldingVerifierChannel<E, H, V>, H, R, V>> = Vec::with_capacity(num_worker);
         let mut folding_verifier_channels = Vec::with_capacity(num_worker);
 
-        // For each folding proof, instantiate a FoldingVerifier to verify it.
+        // For each folding proof, instantiate a FoldingVerifier to verify it, using that
+        // worker's own domain size and degree bound rather than one shared across all workers.
         let worker_layer_commitments = proof.worker_layer_commitments().to_vec();
-        for (folding_proof, layer_commitments) in folding_proofs.iter().zip(worker_layer_commitments.into_iter()) {
+        for (i, (folding_proof, layer_commitments)) in folding_proofs.iter().zip(worker_layer_commitments.into_iter()).enumerate() {
+            let worker_domain_size = self.worker_domain_sizes[i];
+
             // Prepare a verifier channal for the FoldingVerifier
             let mut channel = FoldingVerifierChannel::<E, H, V>::new(
                 folding_proof,
                 layer_commitments,
-                self.worker_domain_size,
+                worker_domain_size,
                 self.folding_factor(),
             )
             .unwrap();
 
-            // Instantiate the folding verifier
-            let last_poly_max_degree = self.master_degree_bound - 1;
+            // Instantiate the folding verifier, folding down to this worker's own ending degree
+            // rather than a degree shared across every worker.
+            let last_poly_max_degree = self.worker_last_poly_max_degrees[i];
             let options = FoldingOptions::new(
-                self.options.blowup_factor(), 
-                self.folding_factor(), 
-                self.worker_domain_size, 
-                last_poly_max_degree);
+                self.options.blowup_factor(),
+                self.folding_factor(),
+                worker_domain_size,
+                last_poly_max_degree)
+            .expect("invalid folding options");
             let mut public_coin = RandomCoin::new(&[]);
-            let verifier = FoldingVerifier::new(&mut channel, &mut public_coin, options, self.worker_degree_bound - 1)?;
-            
+            let verifier = FoldingVerifier::new(
+                &mut channel,
+                &mut public_coin,
+                options,
+                self.worker_degree_bounds[i] - 1,
+                folding_proof.folding_schedule().clone(),
+            )?;
+
             folding_verifiers.push(verifier);
             folding_verifier_channels.push(channel);
         }
@@ -211,86 +428,195 @@ where
         
         // ------------------- Step 2: Verify the batched FRI proof ----------------------------------------
 
-        // Extracts the function commitments for the reconstruction of the batched FRI proof later on. 
-        // The function commitments are the commitments of the evaluation vectors at the worker nodes'
-        // last FRI layers.
-        let mut function_commitments : Vec<H::Digest> = Vec::with_capacity(num_worker);
-        for commitments_vec in proof.worker_layer_commitments() {
-
-            // The function commitment of each worker node is the layer commitment of its last FRI layer.
-            function_commitments.push(*commitments_vec.last().expect("Failed to extract the last layer commitment."));
-        }
-
-        // Reconstruct a batched FRI proof from the FoldAndBatchProof
-        let batching_proofs : Vec<FriProofLayer> = folding_proofs.iter().map(|folding_proof| folding_proof.batching_proof().clone()).collect();
+        // Reconstruct a batched FRI proof from the FoldAndBatchProof. Every worker's last layer is
+        // committed (and opened) separately, one commitment/opening per worker, so these pass
+        // straight through rather than being wrapped into single-element vectors.
         let batched_fri_proof : BatchedFriProof<H> = BatchedFriProof::new(
-            proof.fri_proof().clone(), 
-            proof.master_evaluations().to_vec(), 
-            batching_proofs, 
-            proof.master_layer_commitments().to_vec(), 
-            function_commitments);
+            proof.fri_proof().clone(),
+            proof.master_evaluations().to_vec(),
+            proof.function_openings().clone(),
+            proof.master_layer_commitments().to_vec(),
+            proof.function_commitments().clone(),
+            proof.pow_nonce());
+
+
+        // Verify the batched FRI proof. The returned positions are sampled over the largest
+        // worker's domain; a worker with a smaller domain must fold them down to its own size.
+        let sampling_domain_size = self.max_worker_domain_size();
+        let blinding_evaluations = proof.parse_blinding_evaluations::<E>()?;
+        let blinding_evaluations = if blinding_evaluations.is_empty() { None } else { Some(blinding_evaluations) };
+        let worker_remainder_coefficients = proof.parse_worker_remainder_coefficients::<E>()?;
+        let worker_query_positions = self.verify_batched_fri(batched_fri_proof, proof.worker_layer_commitments().to_vec(), blinding_evaluations, worker_remainder_coefficients)?;
+
+        // If the prover attached the master's remainder as evaluations over a canonical point set
+        // (see [FoldAndBatchProof::with_master_remainder](crate::FoldAndBatchProof::with_master_remainder)),
+        // cross-check it against the coefficients already embedded in `proof.fri_proof()` via
+        // Lagrange interpolation, at a point drawn from the public coin after it so a prover
+        // cannot pick a remainder that only agrees with the coefficients at the canonical points
+        // themselves. Absent on a proof built without this side channel.
+        let (remainder_points, remainder_evaluations) = proof.parse_master_remainder::<E>()?;
+        if !remainder_points.is_empty() {
+            let remainder_coefficients = proof.fri_proof().parse_remainder::<E>().map_err(|err| {
+                VerifierError::InvalidValueInEvaluationsVector(err.to_string())
+            })?;
+            let final_point: E = self.public_coin.draw().expect("failed to draw a remainder spot-check point");
+            let final_value = evaluate_poly_horner(&remainder_coefficients, final_point);
+            self.verify_remainder_by_interpolation(&remainder_points, &remainder_evaluations, &[final_point], &[final_value])?;
+        }
 
 
-        // Verify the batched FRI proof
-        let worker_query_positions = self.verify_batched_fri(batched_fri_proof, proof.worker_layer_commitments().to_vec())?;
-        
-            
         // ------------------- Step 3: Verify the folding proofs ----------------------------------------
 
-        for i in 0..num_worker {
-            folding_verifiers[i].verify(&mut folding_verifier_channels[i], &proof.worker_evaluations()[i], &worker_query_positions)?
-        }
+        // A worker's own evaluations at the query positions may be erasure-coded (see
+        // [FoldAndBatchProof::with_worker_evaluation_parity]): the prover marks a worker whose
+        // evaluations went missing by writing an empty vector for it on the wire. Rebuild every
+        // missing entry from the surviving worker vectors and the attached parity vectors before
+        // replaying the per-worker folding proofs below, so a dropped worker is tolerated
+        // transparently by the rest of this step.
+        let mut worker_evaluations = proof.parse_worker_evaluations::<E>()?;
+        let missing: Vec<usize> = worker_evaluations
+            .iter()
+            .enumerate()
+            .filter_map(|(i, w)| if w.is_empty() { Some(i) } else { None })
+            .collect();
+        if !missing.is_empty() {
+            let (parity_evaluations, parity_points) = proof.parse_worker_evaluation_parity::<E>()?;
+            if missing.len() > parity_evaluations.len() {
+                return Err(VerifierError::InvalidValueInEvaluationsVector(format!(
+                    "{} worker evaluation vectors are missing but only {} parity vectors were attached",
+                    missing.len(),
+                    parity_evaluations.len()
+                )));
+            }
 
-        Ok(())
-    } 
+            let worker_evaluations_with_gaps: Vec<Option<Vec<E>>> = worker_evaluations
+                .iter()
+                .map(|w| if w.is_empty() { None } else { Some(w.clone()) })
+                .collect();
+            let reconstructed = reconstruct_worker_evaluations(&worker_evaluations_with_gaps, &parity_evaluations, &parity_points)?;
+            for &i in missing.iter() {
+                worker_evaluations[i] = reconstructed[i].clone();
+            }
+        }
 
+        // Every worker's folding proof is verified independently of every other's, so with the
+        // `concurrent` feature enabled this runs over `rayon`'s thread pool.
+        let verify_one = |i: usize, verifier: &mut FoldingVerifier<E, FoldingVerifierChannel<E, H, V>, H, R, V>, channel: &mut FoldingVerifierChannel<E, H, V>| -> Result<(), VerifierError> {
+            let positions = self.fold_query_positions(&worker_query_positions, sampling_domain_size, self.worker_domain_sizes[i]);
+            verifier.verify(channel, &worker_evaluations[i], &positions)?;
+            Ok(())
+        };
+
+        #[cfg(feature = "concurrent")]
+        let results: Vec<Result<(), VerifierError>> = folding_verifiers
+            .par_iter_mut()
+            .zip(folding_verifier_channels.par_iter_mut())
+            .enumerate()
+            .map(|(i, (verifier, channel))| verify_one(i, verifier, channel))
+            .collect();
+        #[cfg(not(feature = "concurrent"))]
+        let results: Vec<Result<(), VerifierError>> = folding_verifiers
+            .iter_mut()
+            .zip(folding_verifier_channels.iter_mut())
+            .enumerate()
+            .map(|(i, (verifier, channel))| verify_one(i, verifier, channel))
+            .collect();
+
+        results.into_iter().collect::<Result<(), VerifierError>>()?;
 
-    /// Helper function to extract the queried values and opening proofs from the `batching_proofs` of
-    /// a [BatchedFriProof].
-    fn parse_batching_proofs(&self, batching_proofs: Vec<FriProofLayer>) -> Result<(Vec<Vec<E>>, Vec<V::MultiProof>), VerifierError>  {
-        
-        let num_poly = batching_proofs.len();
-        let mut queried_values : Vec<Vec<E>> = Vec::with_capacity(num_poly);
-        let mut opening_proofs : Vec<V::MultiProof> = Vec::with_capacity(num_poly);
-
-        for layer in batching_proofs {
-            let (values, opening_proof) = layer.parse::<E, H, V>(self.options.folding_factor()).map_err(|err| VerifierError::FunctionOpeningsDeserializationError(err.to_string()))?;
-            queried_values.push(values);
-            opening_proofs.push(opening_proof);
-        }
-        Ok((queried_values, opening_proofs))
+        Ok(())
     }
 
 
-    fn verify_opening_proofs<const N: usize>(&self, function_commitments: &[H::Digest], queried_values: &Vec<Vec<E>>, opening_proofs: &Vec<V::MultiProof>, query_positions: &[usize]) -> Result<(), VerifierError> {
+    /// Verifies one worker's own opening proof for its last-layer evaluations against
+    /// `function_commitment`, a plain per-polynomial vector commitment exactly like
+    /// [BatchedFriVerifier::verify_opening_proofs](crate::batched_verifier::BatchedFriVerifier)
+    /// checks for a standalone batched FRI proof, rather than one shared tree spanning every
+    /// worker.
+    ///
+    /// `positions` are query positions already folded down to `domain_size`, this worker's own
+    /// function domain size. Returns this worker's evaluations at `positions`, recovered from the
+    /// opening's flat, leaf-ordered values via
+    /// [extract_evaluations](crate::batched_verifier::extract_evaluations).
+    fn verify_function_opening(
+        &self,
+        function_commitment: H::Digest,
+        function_opening: &FriProofLayer,
+        domain_size: usize,
+        positions: &[usize],
+    ) -> Result<Vec<E>, VerifierError> {
+        let folding_factor = self.folding_factor();
 
-        assert_eq!(function_commitments.len(), queried_values.len(), "The number of function commitments does not match the number of queried evaluation vectors.");
-        assert_eq!(queried_values.len(), opening_proofs.len(), "The number of queried evaluation vectors does not match the number of opening proofs.");
+        let (values, opening_proof) = function_opening
+            .parse::<E, H, V>(folding_factor)
+            .map_err(|err| VerifierError::FunctionOpeningsDeserializationError(err.to_string()))?;
 
-        let query_positions = fold_positions(query_positions, self.master_domain_size, self.folding_factor());
+        let folded_positions = fold_positions(positions, domain_size, folding_factor);
 
-        for i in 0..function_commitments.len() {
+        let hashed_values: Vec<H::Digest> = match folding_factor {
+            2 => hash_rows::<E, H, 2>(&values),
+            4 => hash_rows::<E, H, 4>(&values),
+            8 => hash_rows::<E, H, 8>(&values),
+            16 => hash_rows::<E, H, 16>(&values),
+            _ => unimplemented!("folding factor {} is not supported", folding_factor),
+        };
 
-            // build the values (i.e., polynomial evaluations over a coset of a multiplicative subgroup
-            // of the current evaluation domain) corresponding to each leaf of the layer commitment
-            let leaf_values : &[[E; N]] = group_slice_elements(&queried_values[i]);
+        V::verify_many(function_commitment, &folded_positions, &hashed_values, &opening_proof)
+            .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
 
-            // hash the aforementioned values to get the leaves to be verified against the previously
-            // received commitment
-            let hashed_values: Vec<H::Digest> = leaf_values
-                .iter()
-                .map(|seg| H::hash_elements(seg))
-                .collect();
+        let mut unbatched_evaluations = extract_evaluations(positions, &vec![values], domain_size, folding_factor);
+        Ok(unbatched_evaluations.remove(0))
+    }
 
-            V::verify_many(
-                function_commitments[i],
-                &query_positions,
-                &hashed_values,
-                &opening_proofs[i],
-            )
-            .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
+    /// Verifies worker `worker_index`'s last layer directly from `coefficients`, the output of
+    /// that worker's [interpolate_last_layer](crate::FoldingProver::interpolate_last_layer) (see
+    /// [FoldAndBatchProof::with_worker_remainder_coefficients](crate::FoldAndBatchProof::with_worker_remainder_coefficients)),
+    /// rather than opening a Merkle proof against its function commitment as
+    /// [verify_function_opening](Self::verify_function_opening) does.
+    ///
+    /// `positions` are query positions already folded down to `domain_size`, this worker's own
+    /// function domain size. Returns this worker's evaluations at `positions`, obtained by
+    /// re-evaluating `coefficients` via [evaluate_poly_horner] at the corresponding domain points,
+    /// exactly as the worker would have computed them before interpolating.
+    ///
+    /// # Errors
+    /// Returns [VerifierError::InvalidPolynomialBatching] if `coefficients` does not have exactly
+    /// one entry per degree allowed by this worker's own `last_poly_max_degree`, i.e. if the
+    /// worker's self-certified last layer does not actually respect its claimed degree bound.
+    fn verify_interpolated_remainder(
+        &self,
+        coefficients: &[E],
+        worker_index: usize,
+        domain_size: usize,
+        positions: &[usize],
+    ) -> Result<Vec<E>, VerifierError> {
+        let expected_num_coefficients = self.worker_last_poly_max_degrees[worker_index] + 1;
+        if coefficients.len() != expected_num_coefficients {
+            return Err(VerifierError::InvalidPolynomialBatching);
         }
-        
-        Ok(())
+
+        let domain_offset = E::BaseField::GENERATOR;
+        let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+        let values = positions
+            .iter()
+            .map(|&position| {
+                let x = E::from(domain_offset * g.exp((position as u64).into()));
+                evaluate_poly_horner(coefficients, x)
+            })
+            .collect();
+
+        Ok(values)
     }
 }
+
+/// Hashes `values`, grouped into `N`-wide leaves, with `H`.
+fn hash_rows<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>, const N: usize>(values: &[E]) -> Vec<H::Digest> {
+    let rows: &[[E; N]] = group_slice_elements(values);
+    rows.iter().map(|row| H::hash_elements(row)).collect()
+}
+
+/// Evaluates the polynomial with `coefficients` (lowest degree first) at `x` using Horner's
+/// method.
+fn evaluate_poly_horner<E: FieldElement>(coefficients: &[E], x: E) -> E {
+    coefficients.iter().rev().fold(E::ZERO, |acc, &coefficient| acc * x + coefficient)
+}
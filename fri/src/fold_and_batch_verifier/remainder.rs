@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+use math::FieldElement;
+
+use crate::VerifierError;
+
+// REMAINDER RECONSTRUCTION
+// ================================================================================================
+//
+// When the master prover's `max_remainder_degree` (see `FriOptions`) is non-zero, folding stops
+// before the codeword is reduced to a single constant, and the remainder polynomial is sent to
+// the verifier as either explicit coefficients or, as here, as evaluations over a small canonical
+// point set. In the latter case the verifier must reconstruct the remainder's value at the
+// queried positions itself, by Lagrange-interpolating the degree-`d` polynomial from `d + 1`
+// point/evaluation pairs.
+
+/// Returns the value at `x` of the unique polynomial of degree at most `xs.len() - 1` that passes
+/// through every `(xs[i], ys[i])` pair.
+///
+/// This evaluates the interpolant directly (rather than first recovering its coefficients) using
+/// the standard Lagrange formula: for each node `j`, the denominator `∏_{k≠j}(x_j - x_k)` is
+/// batch-inverted up front, and the result is accumulated as
+/// `Σ_j ys[j] · ∏_{k≠j}(x - x_k) / ∏_{k≠j}(x_j - x_k)`.
+///
+/// Returns [VerifierError::InvalidPolynomialBatching] if `xs` contains any duplicate points: a
+/// malicious proof could otherwise supply coincident canonical points to make the interpolation
+/// degenerate, and the point set sent by the prover must be disjoint for the reconstruction to be
+/// meaningful.
+///
+/// # Panics
+/// Panics if `xs` and `ys` have different lengths, or if `xs` is empty.
+pub fn lagrange_interpolate_eval<E: FieldElement>(xs: &[E], ys: &[E], x: E) -> Result<E, VerifierError> {
+    assert_eq!(xs.len(), ys.len(), "number of x-coordinates must match the number of evaluations");
+    assert!(!xs.is_empty(), "at least one point is required for interpolation");
+
+    let n = xs.len();
+    for j in 0..n {
+        for k in (j + 1)..n {
+            if xs[j] == xs[k] {
+                return Err(VerifierError::InvalidPolynomialBatching);
+            }
+        }
+    }
+
+    // per-point denominators: prod_{k != j} (x_j - x_k)
+    let denominators: Vec<E> = (0..n)
+        .map(|j| {
+            let mut prod = E::ONE;
+            for k in 0..n {
+                if k != j {
+                    prod *= xs[j] - xs[k];
+                }
+            }
+            prod
+        })
+        .collect();
+    let inv_denominators = batch_inverse(&denominators);
+
+    let mut result = E::ZERO;
+    for j in 0..n {
+        let mut numerator = E::ONE;
+        for k in 0..n {
+            if k != j {
+                numerator *= x - xs[k];
+            }
+        }
+        result += ys[j] * numerator * inv_denominators[j];
+    }
+    Ok(result)
+}
+
+/// Inverts every element of `values` using a single field inversion, via the standard
+/// running-product trick: a forward pass accumulates prefix products, one inversion is taken of
+/// the total product, and a backward pass peels individual inverses back off.
+///
+/// # Panics
+/// Panics if any element of `values` is zero.
+fn batch_inverse<E: FieldElement>(values: &[E]) -> Vec<E> {
+    assert!(values.iter().all(|v| *v != E::ZERO), "cannot invert a zero field element");
+
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values {
+        prefix_products.push(acc);
+        acc *= value;
+    }
+
+    let mut inv_acc = acc.inv();
+    let mut result = alloc::vec![E::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = inv_acc * prefix_products[i];
+        inv_acc *= values[i];
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lagrange_interpolate_eval;
+    use crate::VerifierError;
+    use math::{fields::f128::BaseElement, FieldElement, StarkField};
+
+    #[test]
+    fn test_lagrange_interpolate_recovers_known_polynomial() {
+        // p(x) = 3 + 2x + x^2
+        let p = |x: u128| BaseElement::new(3) + BaseElement::new(2) * BaseElement::new(x) + BaseElement::new(x) * BaseElement::new(x);
+
+        let xs = Vec::from([1u128, 2, 3].map(BaseElement::new));
+        let ys: Vec<BaseElement> = xs.iter().map(|&x| p(x.as_int())).collect();
+
+        for test_x in [0u128, 4, 10, 100] {
+            let expected = p(test_x);
+            let actual = lagrange_interpolate_eval(&xs, &ys, BaseElement::new(test_x)).unwrap();
+            assert_eq!(expected, actual, "interpolated value does not match the original polynomial at x = {test_x}");
+        }
+    }
+
+    #[test]
+    fn test_lagrange_interpolate_rejects_duplicate_points() {
+        let xs = Vec::from([1u128, 1].map(BaseElement::new));
+        let ys = Vec::from([2u128, 3].map(BaseElement::new));
+        let result = lagrange_interpolate_eval(&xs, &ys, BaseElement::new(5));
+        assert!(matches!(result, Err(VerifierError::InvalidPolynomialBatching)));
+    }
+}
@@ -0,0 +1,89 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crypto::{ElementHasher, RandomCoin};
+use math::FieldElement;
+
+use crate::transcript::grind_random_coin;
+use crate::ProverChannel;
+
+/// Prover-side channel for [FoldingPcs](super::FoldingPcs), modeled on
+/// [FriPcsProverChannel](crate::fri_pcs::channel::FriPcsProverChannel): in addition to the FRI
+/// layer commitments every [ProverChannel] tracks, this channel also records the function
+/// commitments pushed by [commit](super::FoldingPcs::commit) and
+/// [open](super::FoldingPcs::open) -- the latter binding the quotient's own last layer, since a
+/// [FoldingProver](crate::FoldingProver) never commits that layer itself -- so that every query
+/// position drawn afterwards is bound to both.
+pub struct FoldingPcsProverChannel<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    public_coin: R,
+    layer_commitments: Vec<H::Digest>,
+    _field_element: PhantomData<E>,
+}
+
+impl<E, H, R> FoldingPcsProverChannel<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    pub fn new() -> Self {
+        FoldingPcsProverChannel {
+            public_coin: RandomCoin::new(&[]),
+            layer_commitments: Vec::new(),
+            _field_element: PhantomData,
+        }
+    }
+
+    pub fn layer_commitments(&self) -> &[H::Digest] {
+        &self.layer_commitments
+    }
+
+    /// Reseeds the transcript with a function commitment -- either the polynomial committed by
+    /// [commit](super::FoldingPcs::commit) or a quotient's own last layer committed during
+    /// [open](super::FoldingPcs::open) -- so every later challenge depends on it.
+    pub fn push_function_commitment(&mut self, function_root: H::Digest) {
+        self.public_coin.reseed(function_root);
+    }
+
+    pub fn draw_query_positions(&mut self, domain_size: usize, num_queries: usize, nonce: u64) -> Vec<usize> {
+        assert!(domain_size >= 8, "domain size must be at least 8, but was {domain_size}");
+        assert!(
+            domain_size.is_power_of_two(),
+            "domain size must be a power of two, but was {domain_size}"
+        );
+        assert!(num_queries > 0, "number of queries must be greater than zero");
+
+        self.public_coin
+            .draw_integers(num_queries, domain_size, nonce)
+            .expect("failed to draw FRI-PCS query positions")
+    }
+
+    /// Finds a proof-of-work nonce satisfying `grinding_factor` and reseeds the public coin with
+    /// it. See [grind_random_coin].
+    pub fn grind_query_seed(&mut self, grinding_factor: u32) -> u64 {
+        grind_random_coin(&mut self.public_coin, grinding_factor)
+    }
+}
+
+impl<E, H, R> ProverChannel<E> for FoldingPcsProverChannel<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    type Hasher = H;
+
+    fn commit_fri_layer(&mut self, layer_root: H::Digest) {
+        self.layer_commitments.push(layer_root);
+        self.public_coin.reseed(layer_root);
+    }
+
+    fn draw_fri_alpha(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw FRI alpha")
+    }
+}
@@ -0,0 +1,453 @@
+use alloc::string::ToString;
+use alloc::{vec, vec::Vec};
+
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use math::{fft, FieldElement, StarkField};
+use utils::{flatten_vector_elements, group_slice_elements, transpose_slice};
+
+use crate::batched_verifier::extract_evaluations;
+use crate::fold_and_batch_prover::{fold_query_positions, FoldingOptions};
+use crate::fold_and_batch_verifier::FoldingVerifier;
+use crate::folding::fold_positions;
+use crate::prover::query_layer;
+use crate::transcript::verify_grinding;
+use crate::{
+    build_layer_commitment, FoldingProver, FoldingVerifierChannel, FriLayer, FriProofLayer,
+    VerifierError,
+};
+
+mod channel;
+use channel::FoldingPcsProverChannel;
+
+mod proof;
+pub use proof::FoldingEvalProof;
+
+#[cfg(test)]
+mod tests;
+
+// FOLDING POLYNOMIAL COMMITMENT SCHEME
+// ================================================================================================
+
+/// A univariate polynomial commitment scheme built on [FoldingProver]/[FoldingOptions], the
+/// distributed half of the Fold-and-Batch protocol, as [FriPcs](crate::fri_pcs::FriPcs) is built
+/// on [FriProver](crate::FriProver)/[FriVerifier](crate::FriVerifier).
+///
+/// [commit](Self::commit) binds a polynomial's evaluations over the LDE domain with a single
+/// vector commitment -- a "function layer" in the same sense as
+/// [BatchedFriProver](crate::batched_prover::BatchedFriProver)'s -- and [open](Self::open) proves
+/// that the committed polynomial takes a claimed value at a point `z` outside that domain. As in
+/// [FriPcs::open](crate::fri_pcs::FriPcs::open), the proof works by reducing the polynomial `f`
+/// against `z` into the quotient `q(x) = (f(x) - f(z)) / (x - z)`, which has degree one less than
+/// `f` and is a valid polynomial (rather than merely a rational function) exactly when `f(z)` is
+/// correct; unlike [FriPcs], which runs a full [FriProver] (remainder included) on the quotient,
+/// this type runs it through a [FoldingProver] instead, so the quotient's own last layer is
+/// committed directly here rather than relying on a remainder the prover would otherwise send in
+/// the clear, since [FoldingProver::build_layers] never commits that layer itself (the
+/// Fold-and-Batch protocol instead leaves that to the master node, which this type has no
+/// equivalent of).
+///
+/// A single [FoldingPcs] commits to exactly one polynomial at a time: once [commit](Self::commit)
+/// has been called, [open](Self::open) may be called any number of times (at different points)
+/// against that same commitment, but a second [commit](Self::commit) call is not supported.
+pub struct FoldingPcs<E, H, V, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    options: FoldingOptions,
+    degree_bound: usize,
+    num_queries: usize,
+    channel: FoldingPcsProverChannel<E, H, R>,
+    committed_evaluations: Option<Vec<E>>,
+    function_layer: Option<FriLayer<E, H, V>>,
+}
+
+impl<E, H, V, R> FoldingPcs<E, H, V, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [FoldingPcs] for committing to a polynomial of at most `degree_bound`
+    /// coefficients (i.e. degree less than `degree_bound`), with `num_queries` queries per opening.
+    ///
+    /// `options` configures the low-degree test that every [open](Self::open) call runs on the
+    /// quotient polynomial, so it must already be set up for the quotient's own degree: its
+    /// `domain_size` must be the committed polynomial's own LDE domain size, and its
+    /// `last_poly_max_degree` must be `degree_bound - 2` (the quotient's own maximum degree,
+    /// which is one less than the committed polynomial's).
+    ///
+    /// # Panics
+    /// Panics if `degree_bound` is less than 2, if `options.domain_size()` does not equal
+    /// `options.blowup_factor() * degree_bound.next_power_of_two()`, or if
+    /// `options.last_poly_max_degree()` does not equal `degree_bound - 2`.
+    pub fn new(options: FoldingOptions, degree_bound: usize, num_queries: usize) -> Self {
+        assert!(
+            degree_bound >= 2,
+            "FoldingPcs requires a degree bound of at least 2 to support point openings"
+        );
+        assert_eq!(
+            options.domain_size(),
+            options.blowup_factor() * degree_bound.next_power_of_two(),
+            "options must be configured over the committed polynomial's own LDE domain"
+        );
+        assert_eq!(
+            options.last_poly_max_degree(),
+            degree_bound - 2,
+            "options must fold the quotient polynomial down to degree_bound - 2, the quotient's own maximum degree"
+        );
+
+        FoldingPcs {
+            options,
+            degree_bound,
+            num_queries,
+            channel: FoldingPcsProverChannel::new(),
+            committed_evaluations: None,
+            function_layer: None,
+        }
+    }
+
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the size of the LDE domain over which the committed polynomial is evaluated.
+    pub fn domain_size(&self) -> usize {
+        self.options.domain_size()
+    }
+
+    /// Returns the folding factor used by the low-degree test run during [open](Self::open).
+    pub fn folding_factor(&self) -> usize {
+        self.options.folding_factor()
+    }
+
+    // COMMIT PHASE
+    // --------------------------------------------------------------------------------------------
+    /// Commits to `evaluations`, a polynomial's evaluations over the full LDE domain, by binding
+    /// them with a vector commitment, as
+    /// [BatchedFriProver::build_function_layer](crate::batched_prover::BatchedFriProver) does for
+    /// a worker's input to batched FRI.
+    ///
+    /// # Panics
+    /// Panics if `evaluations.len()` does not equal [domain_size](Self::domain_size), or if this
+    /// [FoldingPcs] has already committed to a polynomial.
+    pub fn commit(&mut self, evaluations: Vec<E>) -> H::Digest {
+        assert_eq!(
+            evaluations.len(),
+            self.domain_size(),
+            "evaluations must span the full LDE domain"
+        );
+        assert!(
+            self.committed_evaluations.is_none(),
+            "this FoldingPcs has already committed to a polynomial"
+        );
+
+        let (commitment, layer) = self.commit_function_layer(&evaluations);
+
+        self.committed_evaluations = Some(evaluations);
+        self.function_layer = Some(layer);
+
+        commitment
+    }
+
+    /// Commits to a single function layer's `evaluations`, pushing the resulting commitment into
+    /// `self.channel` exactly as
+    /// [build_function_layer](crate::batched_prover::BatchedFriProver) does for batched FRI.
+    fn commit_function_layer(&mut self, evaluations: &[E]) -> (H::Digest, FriLayer<E, H, V>) {
+        match self.folding_factor() {
+            2 => Self::commit_function_layer_impl::<2>(&mut self.channel, evaluations),
+            4 => Self::commit_function_layer_impl::<4>(&mut self.channel, evaluations),
+            8 => Self::commit_function_layer_impl::<8>(&mut self.channel, evaluations),
+            16 => Self::commit_function_layer_impl::<16>(&mut self.channel, evaluations),
+            _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+        }
+    }
+
+    fn commit_function_layer_impl<const N: usize>(
+        channel: &mut FoldingPcsProverChannel<E, H, R>,
+        evaluations: &[E],
+    ) -> (H::Digest, FriLayer<E, H, V>) {
+        let transposed_evaluations = transpose_slice(evaluations);
+        let commitment = build_layer_commitment::<_, _, V, N>(&transposed_evaluations)
+            .expect("failed to construct FRI-PCS function layer commitment");
+        let digest = commitment.commitment();
+        channel.push_function_commitment(digest);
+
+        (digest, FriLayer::new(commitment, flatten_vector_elements(transposed_evaluations)))
+    }
+
+    // OPENING PHASE
+    // --------------------------------------------------------------------------------------------
+    /// Produces a proof that the polynomial committed by [commit](Self::commit) evaluates, at
+    /// `point`, to the value returned alongside it in this method's result.
+    ///
+    /// # Panics
+    /// Panics if no polynomial has been committed yet.
+    pub fn open(&mut self, point: E) -> (E, FoldingEvalProof<H>) {
+        let domain_size = self.domain_size();
+        let domain_offset: E::BaseField = self.options.domain_offset();
+        let folding_factor = self.folding_factor();
+
+        let evaluations = self
+            .committed_evaluations
+            .as_ref()
+            .expect("a commitment must be produced before an opening can be computed");
+        let function_layer = self.function_layer.as_ref().expect("a commitment must be produced before an opening can be computed");
+
+        let value = evaluate_at_point(evaluations, domain_size, domain_offset, point);
+
+        // Build the quotient (f(x) - f(z)) / (x - z) in evaluation form over the whole LDE domain.
+        let xs = domain_values::<E>(domain_size, domain_offset);
+        let inv_denominators = batch_inverse(&xs.iter().map(|&x| x - point).collect::<Vec<_>>());
+        let quotient: Vec<E> = evaluations
+            .iter()
+            .zip(inv_denominators.iter())
+            .map(|(&y, &inv)| (y - value) * inv)
+            .collect();
+
+        // Run the low-degree test on the quotient via the existing distributed folding machinery.
+        // A fresh FoldingProver is used for every opening, since build_layers requires clean
+        // prover state but the same committed polynomial may be opened at several points.
+        let mut quotient_prover = FoldingProver::<E, FoldingPcsProverChannel<E, H, R>, H, V>::new(self.options.clone());
+        let quotient_function_evaluations = quotient_prover.build_layers(&mut self.channel, quotient.clone());
+
+        // A FoldingProver never commits its own last layer -- the master batches it across every
+        // worker's in Fold-and-Batch -- so commit it here directly, exactly as for the committed
+        // polynomial itself in commit().
+        let (quotient_function_commitment, quotient_function_layer) =
+            self.commit_function_layer(&quotient_function_evaluations);
+
+        let pow_nonce = self.channel.grind_query_seed(self.options.grinding_factor());
+        let query_positions = self.channel.draw_query_positions(domain_size, self.num_queries, pow_nonce);
+
+        let (quotient_proof, _) = quotient_prover.build_proof(&quotient, &query_positions, pow_nonce);
+        let quotient_layer_commitments = self.channel.layer_commitments().to_vec();
+
+        let function_opening = self.query_function_layer(function_layer, &query_positions, domain_size);
+
+        let function_domain_size = quotient_function_evaluations.len();
+        let quotient_function_positions =
+            fold_query_positions(&query_positions, domain_size, function_domain_size, folding_factor);
+        let quotient_function_opening =
+            self.query_function_layer(&quotient_function_layer, &quotient_function_positions, function_domain_size);
+
+        let proof = FoldingEvalProof::new(
+            quotient_proof,
+            quotient_layer_commitments,
+            quotient_function_commitment,
+            quotient_function_opening,
+            function_opening,
+        );
+
+        (value, proof)
+    }
+
+    /// Opens a single function layer's evaluations at `positions`, producing a [FriProofLayer]
+    /// that a verifier can check against the matching commitment.
+    fn query_function_layer(&self, layer: &FriLayer<E, H, V>, positions: &[usize], domain_size: usize) -> FriProofLayer {
+        match self.folding_factor() {
+            2 => query_layer::<E, H, V, 2>(layer, positions, domain_size),
+            4 => query_layer::<E, H, V, 4>(layer, positions, domain_size),
+            8 => query_layer::<E, H, V, 8>(layer, positions, domain_size),
+            16 => query_layer::<E, H, V, 16>(layer, positions, domain_size),
+            _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+        }
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+    /// Verifies that `proof` attests to the polynomial bound by `commitment` evaluating to
+    /// `value` at `point`.
+    pub fn verify_opening(
+        &self,
+        commitment: H::Digest,
+        point: E,
+        value: E,
+        proof: &FoldingEvalProof<H>,
+    ) -> Result<(), VerifierError> {
+        let domain_size = self.domain_size();
+        let folding_factor = self.folding_factor();
+
+        // Replay the prover's transcript: the committed polynomial's own function commitment is
+        // absorbed first, exactly as in commit().
+        let mut public_coin = R::new(&[]);
+        public_coin.reseed(commitment);
+
+        let mut channel = FoldingVerifierChannel::<E, H, V>::new(
+            proof.quotient_proof(),
+            proof.quotient_layer_commitments().clone(),
+            domain_size,
+            folding_factor,
+        )
+        .unwrap();
+
+        let mut folding_verifier = FoldingVerifier::new(
+            &mut channel,
+            &mut public_coin,
+            self.options.clone(),
+            self.degree_bound - 2,
+            proof.quotient_proof().folding_schedule().clone(),
+        )?;
+
+        // The quotient's own last layer is committed directly by the prover (see open()), so bind
+        // it to the transcript here in the same order it was bound there, before query positions
+        // are drawn.
+        public_coin.reseed(proof.quotient_function_commitment());
+
+        // If the prover performed proof-of-work grinding, verify the claimed nonce before
+        // sampling query positions from it.
+        let grinding_factor = self.options.grinding_factor();
+        let pow_nonce = proof.quotient_proof().pow_nonce();
+        if !verify_grinding(&mut public_coin, grinding_factor, pow_nonce) {
+            return Err(VerifierError::ProofOfWorkVerificationFailed);
+        }
+
+        let query_positions = public_coin
+            .draw_integers(self.num_queries, domain_size, pow_nonce)
+            .expect("failed to draw FRI-PCS query positions");
+
+        // Authenticate the committed polynomial's own evaluations at the query positions, then
+        // derive the quotient's claimed evaluations from them and the claimed point value, rather
+        // than trusting values handed to us by the prover.
+        let function_evaluations =
+            verify_layer_opening::<E, H, V>(commitment, proof.function_opening(), &query_positions, domain_size, folding_factor)?;
+
+        let domain_offset: E::BaseField = self.options.domain_offset();
+        let quotient_evaluations: Vec<E> = query_positions
+            .iter()
+            .zip(function_evaluations.iter())
+            .map(|(&position, &evaluation)| {
+                let x = domain_point::<E>(domain_size, domain_offset, position);
+                (evaluation - value) * (x - point).inv()
+            })
+            .collect();
+
+        let final_folded_values = folding_verifier.verify(&mut channel, &quotient_evaluations, &query_positions)?;
+
+        // The intermediate layers only establish that the quotient was folded correctly; the
+        // resulting final-layer values must in turn match what the quotient's own function
+        // commitment attests to, since a FoldingVerifier never checks that on its own.
+        let function_domain_size = self.options.final_domain_size();
+        let function_positions = fold_query_positions(&query_positions, domain_size, function_domain_size, folding_factor);
+        let quotient_function_values = verify_layer_opening::<E, H, V>(
+            proof.quotient_function_commitment(),
+            proof.quotient_function_opening(),
+            &function_positions,
+            function_domain_size,
+            folding_factor,
+        )?;
+
+        if final_folded_values != quotient_function_values {
+            return Err(VerifierError::InvalidPolynomialBatching);
+        }
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Evaluates the polynomial with the given `coefficients` at `point` using Horner's method.
+fn evaluate_poly_at<E: FieldElement>(coefficients: &[E], point: E) -> E {
+    coefficients.iter().rev().fold(E::ZERO, |acc, &coefficient| acc * point + coefficient)
+}
+
+/// Recovers `evaluations`' underlying polynomial via an inverse FFT and evaluates it at `point`,
+/// which need not lie in the LDE domain the evaluations were taken over.
+fn evaluate_at_point<E: FieldElement>(evaluations: &[E], domain_size: usize, domain_offset: E::BaseField, point: E) -> E {
+    let mut coefficients = evaluations.to_vec();
+    let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(domain_size);
+    fft::interpolate_poly_with_offset(&mut coefficients, &inv_twiddles, domain_offset);
+    evaluate_poly_at(&coefficients, point)
+}
+
+/// Returns the `position`-th point of the LDE domain of size `domain_size` shifted by
+/// `domain_offset`, i.e. `domain_offset * g^position` where `g` is the domain's generator.
+fn domain_point<E: FieldElement>(domain_size: usize, domain_offset: E::BaseField, position: usize) -> E {
+    let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+    E::from(domain_offset * g.exp((position as u64).into()))
+}
+
+/// Returns every point of the LDE domain of size `domain_size` shifted by `domain_offset`, in
+/// order.
+fn domain_values<E: FieldElement>(domain_size: usize, domain_offset: E::BaseField) -> Vec<E> {
+    let g = E::BaseField::get_root_of_unity(domain_size.ilog2());
+    let mut values = Vec::with_capacity(domain_size);
+    let mut x = domain_offset;
+    for _ in 0..domain_size {
+        values.push(E::from(x));
+        x *= g;
+    }
+    values
+}
+
+/// Inverts every element of `values` using a single field inversion, via the standard
+/// running-product trick.
+///
+/// # Panics
+/// Panics if any element of `values` is zero.
+fn batch_inverse<E: FieldElement>(values: &[E]) -> Vec<E> {
+    assert!(values.iter().all(|v| *v != E::ZERO), "cannot invert a zero field element");
+
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values {
+        prefix_products.push(acc);
+        acc *= value;
+    }
+
+    let mut inv_acc = acc.inv();
+    let mut result = vec![E::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = inv_acc * prefix_products[i];
+        inv_acc *= values[i];
+    }
+    result
+}
+
+/// Opens a single function layer at `positions` and verifies it against `commitment`, as in
+/// [FoldingPcs::query_function_layer](FoldingPcs::query_function_layer), returning the
+/// polynomial's authenticated evaluations at `positions`.
+fn verify_layer_opening<E, H, V>(
+    commitment: H::Digest,
+    opening: &FriProofLayer,
+    positions: &[usize],
+    domain_size: usize,
+    folding_factor: usize,
+) -> Result<Vec<E>, VerifierError>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+{
+    let (values, opening_proof) = opening
+        .parse::<E, H, V>(folding_factor)
+        .map_err(|err| VerifierError::FunctionOpeningsDeserializationError(err.to_string()))?;
+
+    let folded_positions = fold_positions(positions, domain_size, folding_factor);
+    let hashed_values: Vec<H::Digest> = match folding_factor {
+        2 => hash_rows::<E, H, 2>(&values),
+        4 => hash_rows::<E, H, 4>(&values),
+        8 => hash_rows::<E, H, 8>(&values),
+        16 => hash_rows::<E, H, 16>(&values),
+        _ => unimplemented!("folding factor {} is not supported", folding_factor),
+    };
+
+    V::verify_many(commitment, &folded_positions, &hashed_values, &opening_proof)
+        .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
+
+    let mut unbatched_evaluations = extract_evaluations(positions, &vec![values], domain_size, folding_factor);
+    Ok(unbatched_evaluations.remove(0))
+}
+
+/// Hashes `values`, grouped into `N`-wide leaves, with `H`.
+fn hash_rows<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>, const N: usize>(values: &[E]) -> Vec<H::Digest> {
+    let rows: &[[E; N]] = group_slice_elements(values);
+    rows.iter().map(|row| H::hash_elements(row)).collect()
+}
+
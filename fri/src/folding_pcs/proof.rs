@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use crypto::{Digest, ElementHasher};
+
+use crate::fold_and_batch_proof::FoldingProof;
+use crate::FriProofLayer;
+
+/// The output of [FoldingPcs::open](super::FoldingPcs::open): a low-degree proof, via
+/// [FoldingProver](crate::FoldingProver), that the quotient `(f(x) - f(z)) / (x - z)` is a valid
+/// polynomial, together with an opening of the committed polynomial's own evaluations at the same
+/// query positions so the verifier can recompute the quotient's claimed evaluations itself rather
+/// than trusting them from the prover.
+///
+/// A [FoldingProver] never commits its own last layer, since in the Fold-and-Batch protocol that
+/// is left to the master node to batch across workers; here there is no master; so this proof
+/// additionally carries a commitment to (and opening of) the quotient's last layer.
+pub struct FoldingEvalProof<H>
+where
+    H: ElementHasher,
+{
+    quotient_proof: FoldingProof,
+    quotient_layer_commitments: Vec<H::Digest>,
+    quotient_function_commitment: H::Digest,
+    quotient_function_opening: FriProofLayer,
+    function_opening: FriProofLayer,
+}
+
+impl<H> FoldingEvalProof<H>
+where
+    H: ElementHasher,
+{
+    pub(crate) fn new(
+        quotient_proof: FoldingProof,
+        quotient_layer_commitments: Vec<H::Digest>,
+        quotient_function_commitment: H::Digest,
+        quotient_function_opening: FriProofLayer,
+        function_opening: FriProofLayer,
+    ) -> Self {
+        FoldingEvalProof {
+            quotient_proof,
+            quotient_layer_commitments,
+            quotient_function_commitment,
+            quotient_function_opening,
+            function_opening,
+        }
+    }
+
+    pub(crate) fn quotient_proof(&self) -> &FoldingProof {
+        &self.quotient_proof
+    }
+
+    pub(crate) fn quotient_layer_commitments(&self) -> &Vec<H::Digest> {
+        &self.quotient_layer_commitments
+    }
+
+    pub(crate) fn quotient_function_commitment(&self) -> H::Digest {
+        self.quotient_function_commitment
+    }
+
+    pub(crate) fn quotient_function_opening(&self) -> &FriProofLayer {
+        &self.quotient_function_opening
+    }
+
+    /// Returns the opening of the committed polynomial's own evaluations at the query positions,
+    /// from which the verifier recomputes the quotient's claimed evaluations.
+    pub(crate) fn function_opening(&self) -> &FriProofLayer {
+        &self.function_opening
+    }
+
+    /// Returns the number of bytes in this proof.
+    pub fn size(&self) -> usize {
+        let commitments_size = self.quotient_layer_commitments.iter().fold(
+            self.quotient_function_commitment.as_bytes().len(),
+            |acc, commitment| acc + commitment.as_bytes().len(),
+        );
+        self.quotient_proof.size()
+            + commitments_size
+            + self.quotient_function_opening.size()
+            + self.function_opening.size()
+    }
+}
@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree};
+use math::{fft, fields::f128::BaseElement, FieldElement};
+use rand_utils::rand_vector;
+
+use super::{evaluate_poly_at, FoldingPcs};
+use crate::fold_and_batch_prover::FoldingOptions;
+
+type Blake3 = Blake3_256<BaseElement>;
+type Pcs = FoldingPcs<BaseElement, Blake3, MerkleTree<Blake3>, DefaultRandomCoin<Blake3>>;
+
+// PROVE/VERIFY TESTS
+// ================================================================================================
+
+#[test]
+fn test_folding_pcs_commit_and_open() {
+    let result = folding_pcs_prove_verify_random(8, 3, 2, 50);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_folding_pcs_rejects_wrong_value() {
+    let degree_bound_e = 8;
+    let lde_blowup_e = 3;
+    let folding_factor = 4;
+    let num_queries = 50;
+
+    let degree_bound = 1 << degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let domain_size = lde_blowup * degree_bound.next_power_of_two();
+    let options = FoldingOptions::new(lde_blowup, folding_factor, domain_size, degree_bound - 2).unwrap();
+
+    let evaluations = build_evaluations(degree_bound, domain_size);
+
+    let mut pcs = Pcs::new(options, degree_bound, num_queries);
+    let commitment = pcs.commit(evaluations);
+
+    let point = rand_vector::<BaseElement>(1)[0];
+    let (value, proof) = pcs.open(point);
+
+    let result = pcs.verify_opening(commitment, point, value + BaseElement::ONE, &proof);
+    assert!(result.is_err(), "verification should reject an incorrect claimed value");
+}
+
+#[test]
+fn test_evaluate_poly_at_matches_known_polynomial() {
+    // p(x) = 3 + 2x + x^2
+    let coefficients = Vec::from([3, 2, 1].map(BaseElement::new));
+    for x in [0u128, 1, 4, 10] {
+        let point = BaseElement::new(x);
+        let expected = BaseElement::new(3) + BaseElement::new(2) * point + point * point;
+        assert_eq!(evaluate_poly_at(&coefficients, point), expected);
+    }
+}
+
+// TEST UTILS
+// ================================================================================================
+
+/// Commits to a random polynomial of `1 << degree_bound_e` coefficients, opens it at a random
+/// point, and verifies the resulting proof.
+fn folding_pcs_prove_verify_random(
+    degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor: usize,
+    num_queries: usize,
+) -> Result<(), crate::VerifierError> {
+    let degree_bound = 1 << degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let domain_size = lde_blowup * degree_bound.next_power_of_two();
+    let options = FoldingOptions::new(lde_blowup, folding_factor, domain_size, degree_bound - 2).unwrap();
+
+    let evaluations = build_evaluations(degree_bound, domain_size);
+
+    let mut pcs = Pcs::new(options, degree_bound, num_queries);
+    let commitment = pcs.commit(evaluations);
+
+    let point = rand_vector::<BaseElement>(1)[0];
+    let (value, proof) = pcs.open(point);
+
+    pcs.verify_opening(commitment, point, value, &proof)
+}
+
+/// Generates the evaluations, over a domain of `domain_size`, of a random polynomial with at
+/// most `degree_bound` coefficients.
+fn build_evaluations(degree_bound: usize, domain_size: usize) -> Vec<BaseElement> {
+    let mut p = rand_vector::<BaseElement>(degree_bound);
+    p.resize(domain_size, BaseElement::ZERO);
+
+    let twiddles = fft::get_twiddles::<BaseElement>(domain_size);
+    fft::evaluate_poly(&mut p, &twiddles);
+    p
+}
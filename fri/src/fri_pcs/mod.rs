@@ -0,0 +1,685 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use math::{fft, FieldElement, StarkField};
+use utils::{flatten_vector_elements, group_slice_elements, transpose_slice};
+
+use crate::batched_prover::combine_poly_evaluations;
+use crate::batched_verifier::extract_evaluations;
+use crate::folding::fold_positions;
+use crate::prover::query_layer;
+use crate::transcript::verify_grinding;
+use crate::{
+    build_layer_commitment, DefaultVerifierChannel, FriLayer, FriOptions, FriProofLayer,
+    FriProver, FriVerifier, VerifierError,
+};
+
+mod channel;
+use channel::FriPcsProverChannel;
+
+mod proof;
+pub use proof::{EvalProof, PcsCommitment};
+
+#[cfg(test)]
+mod tests;
+
+// FRI POLYNOMIAL COMMITMENT SCHEME
+// ================================================================================================
+
+/// A FRI-based univariate polynomial commitment scheme, as in Binius's `fri_pcs` module.
+///
+/// This turns the low-degree test implemented by [FriProver]/[FriVerifier] into a usable
+/// commitment scheme: [commit](FriPcs::commit) binds a set of polynomials (in coefficient form)
+/// via one vector commitment per polynomial, and [open](FriPcs::open) produces a proof that every
+/// committed polynomial takes a claimed value at a given point `z`. The proof works by reducing
+/// every polynomial `f` against `z` into the quotient `(f(x) - f(z)) / (x - z)`, which has degree
+/// one less than `f` and is a valid polynomial (rather than merely a rational function) exactly
+/// when `f(z)` is correct; the quotients of every committed polynomial are combined into a single
+/// codeword with a random reducing factor, and the existing FRI commit/query machinery is run on
+/// that combined quotient to prove it is low-degree. [verify](FriPcs::verify) recomputes the
+/// combined quotient's claimed evaluations at the query positions directly from openings of the
+/// original commitments, so the low-degree test is checked against values the verifier has
+/// authenticated itself rather than values handed to it by the prover.
+///
+/// [open_points](FriPcs::open_points)/[verify_points](FriPcs::verify_points) generalize this to a
+/// whole set of points at once, as in halo2's multi-point opening argument: rather than running
+/// one FRI instance per point, every polynomial's quotient against a shared Lagrange interpolant
+/// over the point set is folded into the same combined codeword, collapsing what would otherwise
+/// be `|points|` separate proofs into one.
+pub struct FriPcs<E, H, V, R>
+where
+    E: FieldElement + StarkField,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    options: FriOptions,
+    degree_bound: usize,
+    domain_size: usize,
+    num_queries: usize,
+    fri_prover: FriProver<E, FriPcsProverChannel<E, H, R>, H, V>,
+    channel: FriPcsProverChannel<E, H, R>,
+    committed_polys: Vec<Vec<E>>,
+    committed_evaluations: Vec<Vec<E>>,
+    committed_layers: Vec<FriLayer<E, H, V>>,
+}
+
+impl<E, H, V, R> FriPcs<E, H, V, R>
+where
+    E: FieldElement + StarkField,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [FriPcs] for committing to polynomials of at most `degree_bound` coefficients
+    /// (i.e. degree less than `degree_bound`), proven over a domain of size `options.blowup_factor()
+    /// * degree_bound.next_power_of_two()` with `num_queries` queries per opening.
+    ///
+    /// # Panics
+    /// Panics if `degree_bound` is less than 2, since a quotient's degree is one less than the
+    /// degree of the polynomial it was divided out of and must remain representable.
+    pub fn new(options: FriOptions, degree_bound: usize, num_queries: usize) -> Self {
+        assert!(
+            degree_bound >= 2,
+            "FriPcs requires a degree bound of at least 2 to support point openings"
+        );
+
+        let domain_size = options.blowup_factor() * degree_bound.next_power_of_two();
+        FriPcs {
+            fri_prover: FriProver::new(options.clone()),
+            channel: FriPcsProverChannel::new(),
+            options,
+            degree_bound,
+            domain_size,
+            num_queries,
+            committed_polys: Vec::new(),
+            committed_evaluations: Vec::new(),
+            committed_layers: Vec::new(),
+        }
+    }
+
+    // ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the folding factor used by the low-degree test run during [open](Self::open).
+    pub fn folding_factor(&self) -> usize {
+        self.options.folding_factor()
+    }
+
+    /// Returns the size of the LDE domain over which committed polynomials are evaluated.
+    pub fn domain_size(&self) -> usize {
+        self.domain_size
+    }
+
+    /// Returns the maximum allowed degree, in the sense expected by [FriVerifier::new], of the
+    /// combined quotient produced during [open](Self::open): dividing a degree `degree_bound - 1`
+    /// polynomial by `(x - z)` yields a quotient of degree `degree_bound - 2`.
+    fn quotient_max_degree(&self) -> usize {
+        self.degree_bound - 2
+    }
+
+    /// Returns the maximum allowed degree of the combined quotient produced during
+    /// [open_points](Self::open_points) when opening `num_points` points at once: dividing a
+    /// degree `degree_bound - 1` polynomial by the degree-`num_points` vanishing polynomial of
+    /// the opening set yields a quotient of degree `degree_bound - 1 - num_points`.
+    fn multi_quotient_max_degree(&self, num_points: usize) -> usize {
+        self.degree_bound - 1 - num_points
+    }
+
+    // COMMIT PHASE
+    // --------------------------------------------------------------------------------------------
+    /// Commits to `polys`, a set of polynomials in coefficient form, by computing each one's
+    /// evaluations over the LDE domain and binding them with a vector commitment.
+    ///
+    /// # Panics
+    /// Panics if `polys` is empty, if any polynomial has more than `degree_bound` coefficients, or
+    /// if this [FriPcs] has already committed to a set of polynomials. [open](Self::open) and
+    /// [open_points](Self::open_points) keep the committed polynomials, so a [FriPcs] commits
+    /// only once.
+    pub fn commit(&mut self, polys: Vec<Vec<E>>) -> PcsCommitment<H> {
+        assert!(!polys.is_empty(), "a commitment requires at least one polynomial");
+        assert!(
+            self.committed_polys.is_empty(),
+            "this FriPcs has already committed to a set of polynomials"
+        );
+        assert!(
+            polys.iter().all(|poly| poly.len() <= self.degree_bound),
+            "every committed polynomial must have at most degree_bound coefficients"
+        );
+
+        let twiddles = fft::get_twiddles::<E>(self.domain_size);
+        for poly in polys {
+            let mut evaluations = poly.clone();
+            evaluations.resize(self.domain_size, E::ZERO);
+            // Scale coefficient i by GENERATOR^i so the FFT yields f(GENERATOR * g^i), i.e. the
+            // evaluations over the shifted LDE domain that domain_point describes.
+            let mut offset_power = E::ONE;
+            for coefficient in evaluations.iter_mut() {
+                *coefficient *= offset_power;
+                offset_power *= E::GENERATOR;
+            }
+            fft::evaluate_poly(&mut evaluations, &twiddles);
+
+            let layer = match self.folding_factor() {
+                2 => Self::commit_function_layer::<2>(&mut self.channel, &evaluations),
+                4 => Self::commit_function_layer::<4>(&mut self.channel, &evaluations),
+                8 => Self::commit_function_layer::<8>(&mut self.channel, &evaluations),
+                16 => Self::commit_function_layer::<16>(&mut self.channel, &evaluations),
+                _ => unimplemented!("folding factor {} is not supported", self.folding_factor()),
+            };
+
+            self.committed_polys.push(poly);
+            self.committed_evaluations.push(evaluations);
+            self.committed_layers.push(layer);
+        }
+
+        PcsCommitment::new(self.channel.function_commitments().to_vec())
+    }
+
+    /// Commits to a single polynomial's `evaluations`, pushing the resulting commitment into
+    /// `channel` exactly as [build_function_layer](crate::batched_prover::BatchedFriProver)
+    /// does for batched FRI.
+    fn commit_function_layer<const N: usize>(
+        channel: &mut FriPcsProverChannel<E, H, R>,
+        evaluations: &[E],
+    ) -> FriLayer<E, H, V> {
+        let transposed_evaluations = transpose_slice(evaluations);
+        let commitment = build_layer_commitment::<_, _, V, N>(&transposed_evaluations)
+            .expect("failed to construct FRI-PCS function layer commitment");
+        channel.push_function_commitment(commitment.commitment());
+
+        FriLayer::new(commitment, flatten_vector_elements(transposed_evaluations))
+    }
+
+    // OPENING PHASE
+    // --------------------------------------------------------------------------------------------
+    /// Produces a proof that every polynomial committed by the last call to [commit](Self::commit)
+    /// evaluates, at `point`, to the value returned alongside it in this method's result.
+    ///
+    /// Returns the claimed evaluation of every committed polynomial at `point`, in commitment
+    /// order, together with the [EvalProof] attesting to them.
+    ///
+    /// # Panics
+    /// Panics if no polynomials have been committed yet.
+    pub fn open(&mut self, point: E) -> (Vec<E>, EvalProof<H>) {
+        assert!(
+            !self.committed_polys.is_empty(),
+            "a commitment must be produced before an opening can be computed"
+        );
+
+        let values: Vec<E> = self
+            .committed_polys
+            .iter()
+            .map(|coefficients| evaluate_poly_at(coefficients, point))
+            .collect();
+
+        // Build the quotient (f(x) - f(z)) / (x - z) of every committed polynomial in evaluation
+        // form over the whole LDE domain.
+        let xs = domain_values::<E>(self.domain_size);
+        let inv_denominators = batch_inverse(&xs.iter().map(|&x| x - point).collect::<Vec<_>>());
+        let quotients: Vec<Vec<E>> = self
+            .committed_evaluations
+            .iter()
+            .zip(values.iter())
+            .map(|(evaluations, &value)| {
+                evaluations
+                    .iter()
+                    .zip(inv_denominators.iter())
+                    .map(|(&y, &inv)| (y - value) * inv)
+                    .collect()
+            })
+            .collect();
+
+        // Combine the quotients into a single codeword, then run the FRI commit/query phases on
+        // it exactly as for any other low-degree test.
+        let challenge = self.channel.draw_combination_challenge();
+        let combined_quotient = combine_poly_evaluations(&quotients, challenge);
+        self.fri_prover.build_layers(&mut self.channel, combined_quotient);
+
+        let pow_nonce = self.channel.grind_query_seed(self.options.grinding_factor());
+        let mut query_positions =
+            self.channel
+                .draw_query_positions(self.domain_size, self.num_queries, pow_nonce);
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        let fri_proof = self.fri_prover.build_proof(&query_positions);
+        let function_openings = self.compute_function_openings(&query_positions);
+        let layer_commitments = self.channel.layer_commitments().to_vec();
+
+        let proof = EvalProof::new(fri_proof, layer_commitments, function_openings, pow_nonce);
+
+        (values, proof)
+    }
+
+    /// Produces a proof that every polynomial committed by the last call to [commit](Self::commit)
+    /// evaluates, at every point of `points`, to the matching entry of this method's returned
+    /// values.
+    ///
+    /// This is the multi-point generalization of [open](Self::open): rather than running one FRI
+    /// instance per point, every committed polynomial `f_i` is reduced against the whole point set
+    /// `points` at once into the quotient `(f_i(x) - I_i(x)) / Z_S(x)`, where `I_i` is the Lagrange
+    /// interpolant of `f_i` over `points` (through the values returned here) and `Z_S(x) = prod_j
+    /// (x - points[j])` is the vanishing polynomial of the opening set, computed via
+    /// [multi_point_quotients]. The per-polynomial quotients are then combined with a single random
+    /// reducing factor and proven low-degree with one FRI commit/query phase, exactly as in
+    /// [open](Self::open).
+    ///
+    /// Returns `values[i][j]`, the claimed evaluation of the `i`-th committed polynomial (in
+    /// commitment order) at `points[j]`, together with the [EvalProof] attesting to them.
+    ///
+    /// # Panics
+    /// Panics if no polynomials have been committed yet, if `points` is empty, or if
+    /// `points.len()` is at least `degree_bound`, since the resulting quotient's degree would no
+    /// longer be representable.
+    pub fn open_points(&mut self, points: &[E]) -> (Vec<Vec<E>>, EvalProof<H>) {
+        assert!(
+            !self.committed_polys.is_empty(),
+            "a commitment must be produced before an opening can be computed"
+        );
+        assert!(!points.is_empty(), "at least one opening point is required");
+        assert!(
+            points.len() < self.degree_bound,
+            "the number of opening points must be less than degree_bound"
+        );
+
+        let values: Vec<Vec<E>> = self
+            .committed_polys
+            .iter()
+            .map(|coefficients| {
+                points.iter().map(|&point| evaluate_poly_at(coefficients, point)).collect()
+            })
+            .collect();
+
+        // Build every committed polynomial's quotient against the shared point set in evaluation
+        // form over the whole LDE domain, then combine them into a single codeword exactly as
+        // combine_poly_evaluations does for single-point openings.
+        let xs = domain_values::<E>(self.domain_size);
+        let quotients = multi_point_quotients(&xs, &self.committed_evaluations, points, &values);
+
+        let challenge = self.channel.draw_combination_challenge();
+        let combined_quotient = combine_poly_evaluations(&quotients, challenge);
+        self.fri_prover.build_layers(&mut self.channel, combined_quotient);
+
+        let pow_nonce = self.channel.grind_query_seed(self.options.grinding_factor());
+        let mut query_positions =
+            self.channel
+                .draw_query_positions(self.domain_size, self.num_queries, pow_nonce);
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        let fri_proof = self.fri_prover.build_proof(&query_positions);
+        let function_openings = self.compute_function_openings(&query_positions);
+        let layer_commitments = self.channel.layer_commitments().to_vec();
+
+        let proof = EvalProof::new(fri_proof, layer_commitments, function_openings, pow_nonce);
+
+        (values, proof)
+    }
+
+    /// Opens every committed polynomial's function layer at `positions`, producing one
+    /// [FriProofLayer] per polynomial that a verifier can check against the matching commitment
+    /// in a [PcsCommitment].
+    fn compute_function_openings(&self, positions: &[usize]) -> Vec<FriProofLayer> {
+        let folding_factor = self.folding_factor();
+        self.committed_layers
+            .iter()
+            .map(|layer| match folding_factor {
+                2 => query_layer::<E, H, V, 2>(layer, positions, self.domain_size),
+                4 => query_layer::<E, H, V, 4>(layer, positions, self.domain_size),
+                8 => query_layer::<E, H, V, 8>(layer, positions, self.domain_size),
+                16 => query_layer::<E, H, V, 16>(layer, positions, self.domain_size),
+                _ => unimplemented!("folding factor {} is not supported", folding_factor),
+            })
+            .collect()
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+    /// Verifies that `proof` attests to every committed polynomial in `commitment` evaluating to
+    /// the matching entry of `values` at `point`.
+    pub fn verify(
+        &self,
+        commitment: &PcsCommitment<H>,
+        point: E,
+        values: &[E],
+        proof: &EvalProof<H>,
+    ) -> Result<(), VerifierError> {
+        assert_eq!(
+            values.len(),
+            commitment.function_commitments().len(),
+            "the number of claimed values must match the number of committed polynomials"
+        );
+
+        // Replay the prover's transcript to recompute the combination challenge.
+        let mut public_coin = R::new(&[]);
+        for &function_commitment in commitment.function_commitments() {
+            public_coin.reseed(function_commitment);
+        }
+        let challenge: E = public_coin.draw().expect("failed to draw FRI-PCS combination challenge");
+
+        let mut channel = DefaultVerifierChannel::<E, H, V>::new(
+            proof.fri_proof().clone(),
+            proof.layer_commitments().to_vec(),
+            self.domain_size,
+            self.folding_factor(),
+        )
+        .unwrap();
+
+        let fri_verifier = FriVerifier::new(
+            &mut channel,
+            &mut public_coin,
+            self.options.clone(),
+            self.quotient_max_degree(),
+        )?;
+
+        // If the prover performed proof-of-work grinding, verify the claimed nonce before
+        // sampling query positions from it.
+        let grinding_factor = self.options.grinding_factor();
+        let pow_nonce = proof.pow_nonce();
+        if !verify_grinding(&mut public_coin, grinding_factor, pow_nonce) {
+            return Err(VerifierError::ProofOfWorkVerificationFailed);
+        }
+
+        let mut query_positions = public_coin
+            .draw_integers(self.num_queries, self.domain_size, pow_nonce)
+            .expect("failed to draw FRI-PCS query positions");
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        // Authenticate every committed polynomial's evaluations at the query positions against
+        // its own commitment.
+        let function_evaluations = self.verify_function_openings(commitment, proof, &query_positions)?;
+
+        // Recompute the combined quotient's claimed evaluations at the query positions from the
+        // authenticated function evaluations and the claimed point values, rather than trusting
+        // values handed to us by the prover.
+        let xs = query_positions
+            .iter()
+            .map(|&position| domain_point::<E>(self.domain_size, position))
+            .collect::<Vec<_>>();
+        let quotients: Vec<Vec<E>> = function_evaluations
+            .iter()
+            .zip(values.iter())
+            .map(|(evaluations_at_queries, &value)| {
+                evaluations_at_queries
+                    .iter()
+                    .zip(xs.iter())
+                    .map(|(&evaluation, &x)| (evaluation - value) * (x - point).inv())
+                    .collect()
+            })
+            .collect();
+        let quotient_evaluations = combine_poly_evaluations(&quotients, challenge);
+
+        fri_verifier.verify(&mut channel, &quotient_evaluations, &query_positions)?;
+
+        Ok(())
+    }
+
+    /// Verifies that `proof` attests to every committed polynomial in `commitment` evaluating to
+    /// the matching entries of `values` at `points`, as produced by [open_points](Self::open_points).
+    ///
+    /// `values[i][j]` is taken to be the `i`-th committed polynomial's claimed evaluation at
+    /// `points[j]`. As in [verify](Self::verify), the combined quotient's claimed evaluations at
+    /// the query positions are recomputed via [multi_point_quotients] from function evaluations
+    /// the verifier has itself authenticated, rather than trusted from the prover.
+    pub fn verify_points(
+        &self,
+        commitment: &PcsCommitment<H>,
+        points: &[E],
+        values: &[Vec<E>],
+        proof: &EvalProof<H>,
+    ) -> Result<(), VerifierError> {
+        assert_eq!(
+            values.len(),
+            commitment.function_commitments().len(),
+            "the number of claimed value vectors must match the number of committed polynomials"
+        );
+        assert!(!points.is_empty(), "at least one opening point is required");
+        assert!(
+            values.iter().all(|poly_values| poly_values.len() == points.len()),
+            "every claimed value vector must have one entry per opening point"
+        );
+
+        // Replay the prover's transcript to recompute the combination challenge.
+        let mut public_coin = R::new(&[]);
+        for &function_commitment in commitment.function_commitments() {
+            public_coin.reseed(function_commitment);
+        }
+        let challenge: E = public_coin.draw().expect("failed to draw FRI-PCS combination challenge");
+
+        let mut channel = DefaultVerifierChannel::<E, H, V>::new(
+            proof.fri_proof().clone(),
+            proof.layer_commitments().to_vec(),
+            self.domain_size,
+            self.folding_factor(),
+        )
+        .unwrap();
+
+        let fri_verifier = FriVerifier::new(
+            &mut channel,
+            &mut public_coin,
+            self.options.clone(),
+            self.multi_quotient_max_degree(points.len()),
+        )?;
+
+        // If the prover performed proof-of-work grinding, verify the claimed nonce before
+        // sampling query positions from it.
+        let grinding_factor = self.options.grinding_factor();
+        let pow_nonce = proof.pow_nonce();
+        if !verify_grinding(&mut public_coin, grinding_factor, pow_nonce) {
+            return Err(VerifierError::ProofOfWorkVerificationFailed);
+        }
+
+        let mut query_positions = public_coin
+            .draw_integers(self.num_queries, self.domain_size, pow_nonce)
+            .expect("failed to draw FRI-PCS query positions");
+        query_positions.sort_unstable();
+        query_positions.dedup();
+
+        // Authenticate every committed polynomial's evaluations at the query positions against
+        // its own commitment.
+        let function_evaluations = self.verify_function_openings(commitment, proof, &query_positions)?;
+
+        // Recompute the combined quotient's claimed evaluations at the query positions from the
+        // authenticated function evaluations and the claimed point values, rather than trusting
+        // values handed to us by the prover.
+        let xs = query_positions
+            .iter()
+            .map(|&position| domain_point::<E>(self.domain_size, position))
+            .collect::<Vec<_>>();
+        let quotients = multi_point_quotients(&xs, &function_evaluations, points, values);
+        let quotient_evaluations = combine_poly_evaluations(&quotients, challenge);
+
+        fri_verifier.verify(&mut channel, &quotient_evaluations, &query_positions)?;
+
+        Ok(())
+    }
+
+    /// Verifies every committed polynomial's opening proof against its commitment in `commitment`,
+    /// and returns the authenticated evaluations at `query_positions`, one vector per polynomial.
+    fn verify_function_openings(
+        &self,
+        commitment: &PcsCommitment<H>,
+        proof: &EvalProof<H>,
+        query_positions: &[usize],
+    ) -> Result<Vec<Vec<E>>, VerifierError> {
+        let function_commitments = commitment.function_commitments();
+        let function_openings = proof.function_openings();
+        assert_eq!(
+            function_commitments.len(),
+            function_openings.len(),
+            "the number of function commitments does not match the number of function openings"
+        );
+
+        let folding_factor = self.folding_factor();
+        let mut queried_values = Vec::with_capacity(function_openings.len());
+        let mut opening_proofs = Vec::with_capacity(function_openings.len());
+        for layer in function_openings {
+            let (values, opening_proof) = layer
+                .parse::<E, H, V>(folding_factor)
+                .map_err(|err| VerifierError::FunctionOpeningsDeserializationError(err.to_string()))?;
+            queried_values.push(values);
+            opening_proofs.push(opening_proof);
+        }
+
+        let folded_positions = fold_positions(query_positions, self.domain_size, folding_factor);
+        match folding_factor {
+            2 => self.verify_opening_proofs::<2>(function_commitments, &queried_values, &opening_proofs, &folded_positions)?,
+            4 => self.verify_opening_proofs::<4>(function_commitments, &queried_values, &opening_proofs, &folded_positions)?,
+            8 => self.verify_opening_proofs::<8>(function_commitments, &queried_values, &opening_proofs, &folded_positions)?,
+            16 => self.verify_opening_proofs::<16>(function_commitments, &queried_values, &opening_proofs, &folded_positions)?,
+            _ => unimplemented!("folding factor {} is not supported", folding_factor),
+        }
+
+        Ok(extract_evaluations(query_positions, &queried_values, self.domain_size, folding_factor))
+    }
+
+    fn verify_opening_proofs<const N: usize>(
+        &self,
+        function_commitments: &[H::Digest],
+        queried_values: &[Vec<E>],
+        opening_proofs: &[V::MultiProof],
+        folded_positions: &[usize],
+    ) -> Result<(), VerifierError> {
+        for ((&function_commitment, values), opening_proof) in
+            function_commitments.iter().zip(queried_values.iter()).zip(opening_proofs.iter())
+        {
+            let leaf_values: &[[E; N]] = group_slice_elements(values);
+            let hashed_values: Vec<H::Digest> = leaf_values.iter().map(|seg| H::hash_elements(seg)).collect();
+
+            V::verify_many(function_commitment, folded_positions, &hashed_values, opening_proof)
+                .map_err(|_| VerifierError::LayerCommitmentMismatch)?;
+        }
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Evaluates the polynomial with the given `coefficients` at `point` using Horner's method.
+fn evaluate_poly_at<E: FieldElement>(coefficients: &[E], point: E) -> E {
+    coefficients.iter().rev().fold(E::ZERO, |acc, &coefficient| acc * point + coefficient)
+}
+
+/// Returns the `position`-th point of the LDE domain of size `domain_size`, i.e.
+/// `GENERATOR * g^position` where `g` is the domain's generator.
+fn domain_point<E: FieldElement + StarkField>(domain_size: usize, position: usize) -> E {
+    let g = E::get_root_of_unity(domain_size.ilog2());
+    E::GENERATOR * g.exp((position as u64).into())
+}
+
+/// Returns every point of the LDE domain of size `domain_size`, in order.
+fn domain_values<E: FieldElement + StarkField>(domain_size: usize) -> Vec<E> {
+    let g = E::get_root_of_unity(domain_size.ilog2());
+    let mut values = Vec::with_capacity(domain_size);
+    let mut x = E::GENERATOR;
+    for _ in 0..domain_size {
+        values.push(x);
+        x *= g;
+    }
+    values
+}
+
+/// Returns the Lagrange barycentric weights `w_j = 1 / prod_{m != j} (points[j] - points[m])` for
+/// the point set `points`. Together with the vanishing polynomial `Z_S(x) = prod_j (x -
+/// points[j])`, these let the interpolant of any polynomial over `points` be evaluated at any `x`
+/// as `I(x) = Z_S(x) * sum_j w_j * y_j / (x - points[j])` without ever forming `I`'s coefficients
+/// explicitly, as in halo2's multi-point opening argument.
+///
+/// # Panics
+/// Panics if `points` contains a repeated value.
+fn barycentric_weights<E: FieldElement>(points: &[E]) -> Vec<E> {
+    let products: Vec<E> = (0..points.len())
+        .map(|j| {
+            (0..points.len())
+                .filter(|&m| m != j)
+                .fold(E::ONE, |acc, m| acc * (points[j] - points[m]))
+        })
+        .collect();
+
+    batch_inverse(&products)
+}
+
+/// For every polynomial whose evaluation vector appears in `evaluations_per_poly`, computes the
+/// evaluation vector, at every point of `xs`, of the quotient `(f(x) - I(x)) / Z_S(x)`: `I` is the
+/// Lagrange interpolant of `f` over `points` through `values_per_poly`'s matching claimed values,
+/// and `Z_S(x) = prod_j (x - points[j])` is the vanishing polynomial of `points`.
+///
+/// Using the barycentric identity `I(x) / Z_S(x) = sum_j w_j * y_j / (x - points[j])` (see
+/// [barycentric_weights]), this reduces to `f(x) / Z_S(x) - sum_j w_j * y_j / (x - points[j])`,
+/// so every quotient evaluation needs only the same handful of per-`x` field inversions - batched
+/// here into a single [batch_inverse] call - regardless of how many polynomials are being opened.
+///
+/// # Panics
+/// Panics if `points` is empty or contains a repeated value, if any `x` in `xs` coincides with a
+/// point in `points`, or if `evaluations_per_poly` and `values_per_poly` are not the same length.
+fn multi_point_quotients<E: FieldElement>(
+    xs: &[E],
+    evaluations_per_poly: &[Vec<E>],
+    points: &[E],
+    values_per_poly: &[Vec<E>],
+) -> Vec<Vec<E>> {
+    assert!(!points.is_empty(), "at least one opening point is required");
+    assert_eq!(
+        evaluations_per_poly.len(), values_per_poly.len(),
+        "the number of evaluation vectors must match the number of claimed value vectors"
+    );
+
+    let weights = barycentric_weights(points);
+
+    // Batch-invert Z_S(x) and every (x - points[j]), for every x in xs, in a single pass.
+    let stride = points.len() + 1;
+    let mut denominators = Vec::with_capacity(xs.len() * stride);
+    for &x in xs {
+        denominators.push(points.iter().fold(E::ONE, |acc, &point| acc * (x - point)));
+        denominators.extend(points.iter().map(|&point| x - point));
+    }
+    let inverses = batch_inverse(&denominators);
+
+    evaluations_per_poly
+        .iter()
+        .zip(values_per_poly.iter())
+        .map(|(evaluations, values)| {
+            evaluations
+                .iter()
+                .enumerate()
+                .map(|(i, &f_x)| {
+                    let inv_z_s = inverses[i * stride];
+                    let interpolant_over_z_s = (0..points.len())
+                        .fold(E::ZERO, |acc, j| acc + weights[j] * values[j] * inverses[i * stride + 1 + j]);
+                    f_x * inv_z_s - interpolant_over_z_s
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Inverts every element of `values` using a single field inversion, via the standard
+/// running-product trick.
+///
+/// # Panics
+/// Panics if any element of `values` is zero.
+fn batch_inverse<E: FieldElement>(values: &[E]) -> Vec<E> {
+    assert!(values.iter().all(|v| *v != E::ZERO), "cannot invert a zero field element");
+
+    let mut prefix_products = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values {
+        prefix_products.push(acc);
+        acc *= value;
+    }
+
+    let mut inv_acc = acc.inv();
+    let mut result = alloc::vec![E::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        result[i] = inv_acc * prefix_products[i];
+        inv_acc *= values[i];
+    }
+    result
+}
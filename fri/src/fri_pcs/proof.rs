@@ -0,0 +1,89 @@
+use alloc::vec::Vec;
+
+use crypto::{Digest, ElementHasher};
+
+use crate::{FriProof, FriProofLayer};
+
+/// The output of [FriPcs::commit](super::FriPcs::commit): one vector commitment per committed
+/// polynomial, binding its full LDE evaluation vector.
+#[derive(Clone)]
+pub struct PcsCommitment<H>
+where
+    H: ElementHasher,
+{
+    function_commitments: Vec<H::Digest>,
+}
+
+impl<H> PcsCommitment<H>
+where
+    H: ElementHasher,
+{
+    pub(crate) fn new(function_commitments: Vec<H::Digest>) -> Self {
+        assert!(!function_commitments.is_empty(), "a commitment must cover at least one polynomial");
+        PcsCommitment { function_commitments }
+    }
+
+    /// Returns the per-polynomial function commitments, in the order the polynomials were passed
+    /// to [FriPcs::commit](super::FriPcs::commit).
+    pub fn function_commitments(&self) -> &[H::Digest] {
+        &self.function_commitments
+    }
+}
+
+/// The output of [FriPcs::open](super::FriPcs::open): a low-degree proof for the combined
+/// quotient `Σ_i alpha^i * (f_i(x) - f_i(z)) / (x - z)`, together with an opening of every
+/// committed polynomial's own evaluations at the same query positions so that the verifier can
+/// recompute the quotient's claimed evaluations itself instead of trusting them from the prover.
+pub struct EvalProof<H>
+where
+    H: ElementHasher,
+{
+    fri_proof: FriProof,
+    layer_commitments: Vec<H::Digest>,
+    function_openings: Vec<FriProofLayer>,
+    pow_nonce: u64,
+}
+
+impl<H> EvalProof<H>
+where
+    H: ElementHasher,
+{
+    pub(crate) fn new(
+        fri_proof: FriProof,
+        layer_commitments: Vec<H::Digest>,
+        function_openings: Vec<FriProofLayer>,
+        pow_nonce: u64,
+    ) -> Self {
+        EvalProof {
+            fri_proof,
+            layer_commitments,
+            function_openings,
+            pow_nonce,
+        }
+    }
+
+    pub(crate) fn fri_proof(&self) -> &FriProof {
+        &self.fri_proof
+    }
+
+    pub(crate) fn layer_commitments(&self) -> &Vec<H::Digest> {
+        &self.layer_commitments
+    }
+
+    pub(crate) fn function_openings(&self) -> &Vec<FriProofLayer> {
+        &self.function_openings
+    }
+
+    pub(crate) fn pow_nonce(&self) -> u64 {
+        self.pow_nonce
+    }
+
+    /// Returns the number of bytes in this proof.
+    pub fn size(&self) -> usize {
+        // + 8 for pow_nonce
+        let function_openings_size = self.function_openings.iter().fold(0, |acc, layer| acc + layer.size());
+        let layer_commitments_size =
+            self.layer_commitments.iter().fold(0, |acc, commitment| acc + commitment.as_bytes().len());
+        self.fri_proof.size() + function_openings_size + layer_commitments_size + 8
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree};
+use math::{fields::f128::BaseElement, FieldElement};
+use rand_utils::rand_vector;
+
+use super::{evaluate_poly_at, FriPcs};
+use crate::{FriOptions, VerifierError};
+
+type Blake3 = Blake3_256<BaseElement>;
+type Pcs = FriPcs<BaseElement, Blake3, MerkleTree<Blake3>, DefaultRandomCoin<Blake3>>;
+
+// PROVE/VERIFY TESTS
+// ================================================================================================
+
+#[test]
+fn test_fri_pcs_single_polynomial() {
+    let result = fri_pcs_prove_verify_random(8, 3, 2, 1, 0, 50);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fri_pcs_multiple_polynomials() {
+    let result = fri_pcs_prove_verify_random(8, 3, 2, 5, 0, 50);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fri_pcs_with_grinding() {
+    let result = fri_pcs_prove_verify_random(8, 3, 2, 3, 8, 50);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fri_pcs_rejects_wrong_value() {
+    let degree_bound = 1 << 8;
+    let lde_blowup = 1 << 3;
+    let folding_factor = 4;
+    let max_remainder_degree = 7;
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+
+    let polys = Vec::from([rand_vector::<BaseElement>(degree_bound)]);
+
+    let mut pcs = Pcs::new(options, degree_bound, 50);
+    let commitment = pcs.commit(polys);
+
+    let point = rand_vector::<BaseElement>(1)[0];
+    let (mut values, proof) = pcs.open(point);
+
+    // Tamper with the claimed value; verification must reject it.
+    values[0] += BaseElement::ONE;
+
+    let result = pcs.verify(&commitment, point, &values, &proof);
+    assert!(result.is_err(), "verification should reject an incorrect claimed value");
+}
+
+#[test]
+fn test_fri_pcs_multi_point_single_polynomial() {
+    let result = fri_pcs_multi_point_prove_verify_random(8, 3, 2, 1, 3, 50);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fri_pcs_multi_point_multiple_polynomials() {
+    let result = fri_pcs_multi_point_prove_verify_random(8, 3, 2, 5, 4, 50);
+    assert!(result.is_ok(), "{:}", result.err().unwrap());
+}
+
+#[test]
+fn test_fri_pcs_multi_point_rejects_wrong_value() {
+    let degree_bound = 1 << 8;
+    let lde_blowup = 1 << 3;
+    let folding_factor = 4;
+    let max_remainder_degree = 7;
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+
+    let polys = Vec::from([rand_vector::<BaseElement>(degree_bound)]);
+
+    let mut pcs = Pcs::new(options, degree_bound, 50);
+    let commitment = pcs.commit(polys);
+
+    let points = rand_vector::<BaseElement>(3);
+    let (mut values, proof) = pcs.open_points(&points);
+
+    // Tamper with one of the claimed values; verification must reject it.
+    values[0][1] += BaseElement::ONE;
+
+    let result = pcs.verify_points(&commitment, &points, &values, &proof);
+    assert!(result.is_err(), "verification should reject an incorrect claimed value");
+}
+
+#[test]
+fn test_evaluate_poly_at_matches_known_polynomial() {
+    // p(x) = 3 + 2x + x^2
+    let coefficients = Vec::from([3, 2, 1].map(BaseElement::new));
+    for x in [0u128, 1, 4, 10] {
+        let point = BaseElement::new(x);
+        let expected = BaseElement::new(3) + BaseElement::new(2) * point + point * point;
+        assert_eq!(evaluate_poly_at(&coefficients, point), expected);
+    }
+}
+
+// TEST UTILS
+// ================================================================================================
+
+/// Generates `num_polys` random polynomials of `degree_bound` coefficients, commits to them,
+/// opens at a random point, and verifies the resulting proof.
+fn fri_pcs_prove_verify_random(
+    degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    num_polys: usize,
+    grinding_factor: u32,
+    num_queries: usize,
+) -> Result<(), VerifierError> {
+    let degree_bound = 1 << degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let max_remainder_degree = 7;
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree)
+        .with_grinding_factor(grinding_factor);
+
+    let mut polys = Vec::with_capacity(num_polys);
+    for _ in 0..num_polys {
+        polys.push(rand_vector::<BaseElement>(degree_bound));
+    }
+
+    let mut pcs = Pcs::new(options, degree_bound, num_queries);
+    let commitment = pcs.commit(polys);
+
+    let point = rand_vector::<BaseElement>(1)[0];
+    let (values, proof) = pcs.open(point);
+
+    pcs.verify(&commitment, point, &values, &proof)
+}
+
+/// Generates `num_polys` random polynomials of `degree_bound` coefficients, commits to them,
+/// opens at `num_points` random points in a single FRI instance, and verifies the resulting proof.
+fn fri_pcs_multi_point_prove_verify_random(
+    degree_bound_e: usize,
+    lde_blowup_e: usize,
+    folding_factor_e: usize,
+    num_polys: usize,
+    num_points: usize,
+    num_queries: usize,
+) -> Result<(), VerifierError> {
+    let degree_bound = 1 << degree_bound_e;
+    let lde_blowup = 1 << lde_blowup_e;
+    let folding_factor = 1 << folding_factor_e;
+    let max_remainder_degree = 7;
+    let options = FriOptions::new(lde_blowup, folding_factor, max_remainder_degree);
+
+    let mut polys = Vec::with_capacity(num_polys);
+    for _ in 0..num_polys {
+        polys.push(rand_vector::<BaseElement>(degree_bound));
+    }
+
+    let mut pcs = Pcs::new(options, degree_bound, num_queries);
+    let commitment = pcs.commit(polys);
+
+    let points = rand_vector::<BaseElement>(num_points);
+    let (values, proof) = pcs.open_points(&points);
+
+    pcs.verify_points(&commitment, &points, &values, &proof)
+}
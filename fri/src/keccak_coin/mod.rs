@@ -0,0 +1,176 @@
+//! An EVM-compatible Fiat-Shamir transcript.
+//!
+//! [DefaultRandomCoin](crate::DefaultRandomCoin) is generic over any [ElementHasher], but the byte
+//! layout of the challenges it derives has no meaning outside this crate: an on-chain verifier
+//! re-implementing the same transcript in Solidity has no way to reproduce it. [Keccak256RandomCoin]
+//! instead hard-codes both the hash function and the byte-level encoding a Solidity verifier would
+//! use: the transcript state is a running Keccak-256 digest that every absorb replaces with
+//! `keccak256(state, data)`, field elements are absorbed as big-endian bytes, and every squeeze
+//! re-hashes the running state together with an incrementing 8-byte counter, exactly as
+//! `keccak256(abi.encodePacked(state, uint64(counter)))` would on-chain.
+//!
+//! [Keccak256_256] is the matching [ElementHasher], so that vector commitments built while proving
+//! can be authenticated against the same transcript a Solidity verifier reconstructs.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crypto::{ElementHasher, Hasher, RandomCoin, RandomCoinError};
+use math::{FieldElement, StarkField};
+use sha3::{Digest as _, Keccak256};
+
+#[cfg(test)]
+mod tests;
+
+const DIGEST_SIZE: usize = 32;
+
+// KECCAK DIGEST
+// ================================================================================================
+
+/// A 256-bit Keccak digest, the type `keccak256` produces in the EVM.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Keccak256Digest([u8; DIGEST_SIZE]);
+
+impl Keccak256Digest {
+    /// Returns the big-endian bytes of this digest, in the order `keccak256` returns them.
+    pub fn as_bytes(&self) -> [u8; DIGEST_SIZE] {
+        self.0
+    }
+}
+
+impl From<[u8; DIGEST_SIZE]> for Keccak256Digest {
+    fn from(bytes: [u8; DIGEST_SIZE]) -> Self {
+        Keccak256Digest(bytes)
+    }
+}
+
+impl From<sha3::digest::generic_array::GenericArray<u8, sha3::digest::consts::U32>> for Keccak256Digest {
+    fn from(bytes: sha3::digest::generic_array::GenericArray<u8, sha3::digest::consts::U32>) -> Self {
+        Keccak256Digest(bytes.into())
+    }
+}
+
+// KECCAK ELEMENT HASHER
+// ================================================================================================
+
+/// An [ElementHasher] backed by Keccak-256, the EVM's native hash function.
+pub struct Keccak256_256<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Keccak256_256<B> {
+    type Digest = Keccak256Digest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        Keccak256::digest(bytes).into()
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        let mut hasher = Keccak256::new();
+        hasher.update(values[0].as_bytes());
+        hasher.update(values[1].as_bytes());
+        hasher.finalize().into()
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        let mut hasher = Keccak256::new();
+        hasher.update(seed.as_bytes());
+        hasher.update(value.to_be_bytes());
+        hasher.finalize().into()
+    }
+}
+
+impl<B: StarkField> ElementHasher for Keccak256_256<B> {
+    type BaseField = B;
+
+    /// Hashes `elements` as the big-endian encoding of each element, in order, matching how a
+    /// Solidity verifier would re-encode the same elements with `abi.encodePacked`.
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        let mut hasher = Keccak256::new();
+        for chunk in E::elements_as_bytes(elements).chunks_exact(E::ELEMENT_BYTES) {
+            let mut be_bytes = chunk.to_vec();
+            be_bytes.reverse();
+            hasher.update(&be_bytes);
+        }
+        hasher.finalize().into()
+    }
+}
+
+// KECCAK RANDOM COIN
+// ================================================================================================
+
+/// A [RandomCoin] implementation whose challenges an on-chain Solidity verifier can reproduce
+/// exactly, by hashing with Keccak-256 and encoding every absorbed or squeezed value big-endian.
+pub struct Keccak256RandomCoin<B: StarkField> {
+    state: Keccak256Digest,
+    counter: u64,
+    _base_field: PhantomData<B>,
+}
+
+impl<B: StarkField> Keccak256RandomCoin<B> {
+    /// Re-hashes the running state together with the current squeeze counter, advancing the
+    /// counter so that the next squeeze (absent an intervening reseed) yields a fresh digest.
+    fn squeeze(&mut self) -> Keccak256Digest {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state.as_bytes());
+        hasher.update(self.counter.to_be_bytes());
+        self.counter += 1;
+        hasher.finalize().into()
+    }
+}
+
+impl<B: StarkField> RandomCoin for Keccak256RandomCoin<B> {
+    type BaseField = B;
+    type Hasher = Keccak256_256<B>;
+
+    fn new(seed: &[u8]) -> Self {
+        Keccak256RandomCoin {
+            state: Keccak256::digest(seed).into(),
+            counter: 0,
+            _base_field: PhantomData,
+        }
+    }
+
+    fn reseed(&mut self, data: Keccak256Digest) {
+        self.state = Keccak256_256::<B>::merge(&[self.state, data]);
+        self.counter = 0;
+    }
+
+    fn reseed_with_int(&mut self, value: u64) {
+        self.state = Keccak256_256::<B>::merge_with_int(self.state, value);
+        self.counter = 0;
+    }
+
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.state.as_bytes());
+        hasher.update(value.to_be_bytes());
+        let digest: Keccak256Digest = hasher.finalize().into();
+        u32::from_be_bytes(digest.as_bytes()[..4].try_into().unwrap()).leading_zeros()
+    }
+
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError> {
+        loop {
+            let bytes = self.squeeze();
+            if let Some(element) = E::from_random_bytes(&bytes.as_bytes()) {
+                return Ok(element);
+            }
+        }
+    }
+
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        assert!(domain_size.is_power_of_two(), "domain size must be a power of two");
+
+        self.reseed_with_int(nonce);
+        let mut values = Vec::with_capacity(num_values);
+        for _ in 0..num_values {
+            let bytes = self.squeeze();
+            let value = u64::from_be_bytes(bytes.as_bytes()[..8].try_into().unwrap());
+            values.push((value as usize) % domain_size);
+        }
+        Ok(values)
+    }
+}
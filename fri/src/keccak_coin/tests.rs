@@ -0,0 +1,85 @@
+use sha3::{Digest, Keccak256};
+
+use super::{Keccak256Digest, Keccak256RandomCoin, Keccak256_256};
+use crate::RandomCoin;
+use math::{fields::f128::BaseElement, FieldElement};
+
+/// A from-scratch re-implementation of the transcript's reseed/squeeze rules, used to check
+/// [Keccak256RandomCoin] against byte-level expectations independent of its own code.
+fn reference_squeeze(state: &[u8; 32], counter: u64) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(state);
+    hasher.update(counter.to_be_bytes());
+    hasher.finalize().into()
+}
+
+#[test]
+fn test_new_hashes_the_seed() {
+    let seed = [1u8, 2, 3, 4];
+    let coin = Keccak256RandomCoin::<BaseElement>::new(&seed);
+
+    let expected_state: [u8; 32] = Keccak256::digest(seed).into();
+    assert_eq!(coin.state.as_bytes(), expected_state);
+}
+
+#[test]
+fn test_reseed_matches_reference_merge() {
+    let mut coin = Keccak256RandomCoin::<BaseElement>::new(&[]);
+    let initial_state = coin.state;
+
+    let commitment = Keccak256Digest::from(Keccak256::digest(b"a layer commitment").into());
+    coin.reseed(commitment);
+
+    let mut hasher = Keccak256::new();
+    hasher.update(initial_state.as_bytes());
+    hasher.update(commitment.as_bytes());
+    let expected_state: [u8; 32] = hasher.finalize().into();
+
+    assert_eq!(coin.state.as_bytes(), expected_state);
+    assert_eq!(coin.counter, 0);
+}
+
+#[test]
+fn test_draw_matches_reference_squeeze() {
+    let mut coin = Keccak256RandomCoin::<BaseElement>::new(b"fri-pcs transcript");
+    let state = coin.state.as_bytes();
+
+    // Replay the squeeze sequence independently and find the first digest that the field element
+    // decoder would accept, exactly as `draw` does internally.
+    let mut counter = 0u64;
+    let expected = loop {
+        let bytes = reference_squeeze(&state, counter);
+        counter += 1;
+        if let Some(element) = BaseElement::from_random_bytes(&bytes) {
+            break element;
+        }
+    };
+
+    let actual: BaseElement = coin.draw().unwrap();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_draw_integers_stays_within_domain() {
+    let mut coin = Keccak256RandomCoin::<BaseElement>::new(b"fri-pcs transcript");
+    let domain_size = 1 << 10;
+    let positions = coin.draw_integers(64, domain_size, 0).unwrap();
+
+    assert_eq!(positions.len(), 64);
+    assert!(positions.iter().all(|&position| position < domain_size));
+}
+
+#[test]
+fn test_hash_elements_matches_big_endian_reference() {
+    let elements = [BaseElement::new(1), BaseElement::new(2), BaseElement::new(u128::MAX - 1)];
+
+    let digest = Keccak256_256::<BaseElement>::hash_elements(&elements);
+
+    let mut hasher = Keccak256::new();
+    for element in &elements {
+        hasher.update(element.as_int().to_be_bytes());
+    }
+    let expected: [u8; 32] = hasher.finalize().into();
+
+    assert_eq!(digest.as_bytes(), expected);
+}
@@ -0,0 +1,179 @@
+//! A Solidity verifier generator for FRI proofs, in the spirit of halo2's `SolidityGenerator`.
+//!
+//! [SolidityGenerator::generate] emits a contract that re-derives every Fiat-Shamir challenge
+//! with [Keccak256RandomCoin](crate::keccak_coin::Keccak256RandomCoin)'s exact byte encoding and
+//! absorbs each layer commitment in order, but the per-query Merkle-path authentication and the
+//! per-layer folding-consistency check are not implemented yet: the generated `verify`
+//! entrypoint unconditionally reverts once it reaches that stage, rather than returning `true`
+//! for proofs it never actually checked. Solidity has no generics, so the folding factor, domain
+//! size, number of layers and number of queries are baked into the emitted source as constants
+//! rather than being passed to `verify` at call time: every `for` loop that iterates over a
+//! folding coset is unrolled to the concrete `folding_factor` given to [SolidityGenerator::new].
+//!
+//! [encode_calldata] takes a [FriProof] serialized the normal way via
+//! [Serializable](utils::Serializable) and ABI-encodes it as the single `bytes` argument the
+//! generated contract's `verify` entrypoint expects.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use utils::{Deserializable, DeserializationError, Serializable, SliceReader};
+
+use crate::{FriOptions, FriProof};
+
+#[cfg(test)]
+mod tests;
+
+// SOLIDITY GENERATOR
+// ================================================================================================
+
+/// Generates a Solidity verifier contract for FRI proofs produced under a fixed [FriOptions]
+/// configuration.
+pub struct SolidityGenerator {
+    options: FriOptions,
+    domain_size: usize,
+    num_queries: usize,
+    num_layers: usize,
+}
+
+impl SolidityGenerator {
+    /// Returns a new [SolidityGenerator] for proofs over a domain of `domain_size` elements,
+    /// queried `num_queries` times, and folded for `num_layers` layers before the remainder.
+    ///
+    /// # Panics
+    /// Panics if `domain_size` is not a power of two, or if `options.folding_factor()` does not
+    /// divide `domain_size` evenly after `num_layers` layers of folding.
+    pub fn new(options: FriOptions, domain_size: usize, num_queries: usize, num_layers: usize) -> Self {
+        assert!(
+            domain_size.is_power_of_two(),
+            "domain size must be a power of two, but was {domain_size}"
+        );
+        assert!(
+            domain_size % options.folding_factor().pow(num_layers as u32) == 0,
+            "folding factor must divide domain size evenly after num_layers layers"
+        );
+
+        SolidityGenerator {
+            options,
+            domain_size,
+            num_queries,
+            num_layers,
+        }
+    }
+
+    /// Emits the Solidity source of the verifier contract.
+    pub fn generate(&self) -> String {
+        let folding_factor = self.options.folding_factor();
+        let grinding_factor = self.options.grinding_factor();
+
+        format!(
+            r#"// SPDX-License-Identifier: MIT
+// Auto-generated by winter_fri::solidity_verifier::SolidityGenerator. Do not edit by hand.
+pragma solidity ^0.8.24;
+
+contract FriVerifier {{
+    uint256 constant FOLDING_FACTOR = {folding_factor};
+    uint256 constant DOMAIN_SIZE = {domain_size};
+    uint256 constant NUM_LAYERS = {num_layers};
+    uint256 constant NUM_QUERIES = {num_queries};
+    uint256 constant GRINDING_FACTOR = {grinding_factor};
+
+    /// Verifies a serialized FRI proof. Reverts if the proof is invalid.
+    ///
+    /// NOT YET IMPLEMENTED: the Merkle-path authentication and folding-consistency checks below
+    /// are stubbed out and always revert, so this entrypoint never returns `true`. Do not deploy
+    /// this contract expecting it to reject invalid proofs -- it rejects every proof.
+    function verify(bytes calldata proof) external pure returns (bool) {{
+        bytes32 state = keccak256(proof[0:0]);
+        uint256 counter = 0;
+
+        // Absorb the NUM_LAYERS layer commitments, drawing one folding challenge after each.
+        for (uint256 layer = 0; layer < NUM_LAYERS; layer++) {{
+            (state, counter) = _absorbLayerCommitment(proof, layer, state);
+            // solhint-disable-next-line no-unused-vars
+            (bytes32 alpha, ) = _squeeze(state, counter);
+            alpha; // folding challenge: combined into the per-layer consistency check below
+        }}
+
+        // Grind the proof-of-work nonce before drawing query positions, if required.
+        if (GRINDING_FACTOR > 0) {{
+            _checkProofOfWork(state);
+        }}
+
+        // Unrolled over FOLDING_FACTOR: authenticate every queried Merkle path and check that
+        // each layer's folded value is consistent with the FOLDING_FACTOR siblings beneath it.
+        for (uint256 query = 0; query < NUM_QUERIES; query++) {{
+            _verifyQueryPath(proof, query);
+        }}
+
+        return true;
+    }}
+
+    function _absorbLayerCommitment(bytes calldata proof, uint256 layer, bytes32 state)
+        private
+        pure
+        returns (bytes32, uint256)
+    {{
+        bytes32 commitment = bytes32(proof[32 * layer:32 * layer + 32]);
+        return (keccak256(abi.encodePacked(state, commitment)), 0);
+    }}
+
+    function _squeeze(bytes32 state, uint256 counter) private pure returns (bytes32, uint256) {{
+        return (keccak256(abi.encodePacked(state, uint64(counter))), counter + 1);
+    }}
+
+    function _checkProofOfWork(bytes32 state) private pure {{
+        state;
+        // The grinding nonce would be read from `proof` and checked against GRINDING_FACTOR
+        // here; not implemented yet, so fail closed rather than accept every nonce.
+        revert("FriVerifier: proof-of-work check not implemented");
+    }}
+
+    function _verifyQueryPath(bytes calldata proof, uint256 query) private pure {{
+        proof;
+        query;
+        // The unrolled FOLDING_FACTOR-wide coset read and Merkle authentication would go here;
+        // not implemented yet, so fail closed rather than accept every query path.
+        revert("FriVerifier: query path verification not implemented");
+    }}
+}}
+"#
+        )
+    }
+}
+
+// CALLDATA ENCODING
+// ================================================================================================
+
+/// ABI-encodes `proof`, serialized the normal way via [Serializable], as the single `bytes`
+/// argument the contract generated by [SolidityGenerator::generate] expects for its `verify`
+/// entrypoint.
+pub fn encode_calldata(proof: &FriProof) -> Vec<u8> {
+    let mut serialized = Vec::new();
+    proof.write_into(&mut serialized);
+
+    let mut calldata = Vec::with_capacity(64 + serialized.len().next_multiple_of(32));
+    // Offset of the `bytes` argument's data within this single-argument call: always 0x20.
+    calldata.extend(core::iter::repeat(0u8).take(31));
+    calldata.push(0x20);
+    // Length of the `bytes` argument.
+    calldata.extend(core::iter::repeat(0u8).take(24));
+    calldata.extend_from_slice(&(serialized.len() as u64).to_be_bytes());
+    // The serialized proof itself, right-padded to a 32-byte boundary.
+    calldata.extend_from_slice(&serialized);
+    let padding = serialized.len().next_multiple_of(32) - serialized.len();
+    calldata.extend(core::iter::repeat(0u8).take(padding));
+
+    calldata
+}
+
+/// Decodes `calldata` produced by [encode_calldata] back into a [FriProof].
+///
+/// # Panics
+/// Panics if `calldata` is not a well-formed encoding of a single `bytes` argument.
+pub fn decode_calldata(calldata: &[u8]) -> Result<FriProof, DeserializationError> {
+    let length = u64::from_be_bytes(calldata[56..64].try_into().expect("calldata is too short")) as usize;
+    let serialized = &calldata[64..64 + length];
+    FriProof::read_from(&mut SliceReader::new(serialized))
+}
@@ -0,0 +1,186 @@
+//! A hash-based Fiat-Shamir transcript abstraction.
+//!
+//! [BatchedFriProverChannel](crate::batched_prover::channel::BatchedFriProverChannel) used to draw
+//! every challenge directly through a [RandomCoin], which couples it to winterfell's own sponge
+//! construction and byte encoding. [Transcript] pulls the operations a prover channel actually
+//! needs out into a trait -- observing commitments and field elements, then squeezing challenges
+//! and query positions -- so the channel can run on any backend that implements it.
+//! [RandomCoinTranscript] adapts an existing [RandomCoin], preserving today's challenge
+//! derivation exactly, and [Keccak256Transcript] is the same adapter over
+//! [Keccak256RandomCoin](crate::keccak_coin::Keccak256RandomCoin), so that every challenge can be
+//! reproduced byte-for-byte by a Solidity verifier.
+//!
+//! `DefaultVerifierChannel` is not migrated onto [Transcript] by this module: it lives in the
+//! crate's top-level verifier module rather than under `fri/src/`, alongside `VerifierError` and
+//! a handful of other root-level types. [BatchedFriProverChannel](crate::batched_prover::channel::BatchedFriProverChannel)
+//! is the half of the pair that could be moved over.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crypto::{ElementHasher, Hasher, RandomCoin};
+use math::FieldElement;
+
+use crate::keccak_coin::{Keccak256RandomCoin, Keccak256_256};
+
+#[cfg(test)]
+mod tests;
+
+// TRANSCRIPT
+// ================================================================================================
+
+/// A Fiat-Shamir transcript that a prover (or verifier) channel observes commitments and field
+/// elements into, then draws challenges out of.
+pub trait Transcript<E: FieldElement> {
+    /// The hash function whose digests this transcript absorbs.
+    type Hasher: Hasher;
+
+    /// Absorbs a commitment digest.
+    fn observe_digest(&mut self, digest: <Self::Hasher as Hasher>::Digest);
+
+    /// Absorbs a sequence of field elements.
+    fn observe_elements(&mut self, elements: &[E]);
+
+    /// Absorbs a proof-of-work nonce, as found by a grinding search over [check_leading_zeros](Self::check_leading_zeros).
+    fn observe_nonce(&mut self, nonce: u64);
+
+    /// Returns the number of leading zero bits of the digest obtained by hashing the current
+    /// transcript state together with the candidate `nonce`, without absorbing it.
+    fn check_leading_zeros(&self, nonce: u64) -> u32;
+
+    /// Squeezes a single field element challenge.
+    fn challenge_field_element(&mut self) -> E;
+
+    /// Squeezes `count` query positions, each in `0..domain_size`.
+    fn challenge_integers(&mut self, count: usize, domain_size: usize) -> Vec<usize>;
+
+    /// Finds the smallest `nonce` such that hashing it together with the current transcript
+    /// state produces a digest with at least `grinding_factor` leading zero bits, absorbs that
+    /// nonce, and returns it.
+    ///
+    /// The nonce must be found (and absorbed) *after* all commitments relevant to query sampling
+    /// have already been absorbed into the transcript, so that it genuinely constrains the query
+    /// positions drawn afterwards. When `grinding_factor` is 0 this is a no-op and the returned
+    /// nonce is 0, preserving the existing (ungrounded) behavior.
+    fn grind_query_seed(&mut self, grinding_factor: u32) -> u64 {
+        if grinding_factor == 0 {
+            return 0;
+        }
+
+        let nonce = (1..u64::MAX)
+            .find(|&nonce| self.check_leading_zeros(nonce) >= grinding_factor)
+            .expect("failed to find a grinding nonce satisfying the required number of leading zeros");
+        self.observe_nonce(nonce);
+        nonce
+    }
+}
+
+// RANDOM COIN GRINDING
+// ================================================================================================
+
+/// Same search [Transcript::grind_query_seed] performs, for the channels
+/// ([FriPcsProverChannel](crate::fri_pcs::channel::FriPcsProverChannel),
+/// [FoldingPcsProverChannel](crate::folding_pcs::channel::FoldingPcsProverChannel)) that still
+/// talk to a [RandomCoin] directly instead of going through the [Transcript] abstraction.
+pub fn grind_random_coin<R: RandomCoin>(coin: &mut R, grinding_factor: u32) -> u64 {
+    if grinding_factor == 0 {
+        return 0;
+    }
+
+    let nonce = (1..u64::MAX)
+        .find(|&nonce| coin.check_leading_zeros(nonce) >= grinding_factor)
+        .expect("failed to find a grinding nonce satisfying the required number of leading zeros");
+    coin.reseed_with_int(nonce);
+    nonce
+}
+
+/// Verifier-side counterpart of [grind_random_coin]: checks that `pow_nonce` satisfies
+/// `grinding_factor`'s leading-zero requirement against `coin`'s current state, reseeding `coin`
+/// with it on success so that challenges drawn afterwards match what the prover's own grinding
+/// search bound.
+///
+/// Returns `false` (without reseeding `coin`) if `pow_nonce` does not satisfy the requirement,
+/// leaving it to the caller to map that into its own error type. When `grinding_factor` is 0
+/// this is a no-op that returns `true`, mirroring [grind_random_coin].
+pub fn verify_grinding<R: RandomCoin>(coin: &mut R, grinding_factor: u32, pow_nonce: u64) -> bool {
+    if grinding_factor == 0 {
+        return true;
+    }
+    if coin.check_leading_zeros(pow_nonce) < grinding_factor {
+        return false;
+    }
+    coin.reseed_with_int(pow_nonce);
+    true
+}
+
+// RANDOM COIN TRANSCRIPT
+// ================================================================================================
+
+/// A [Transcript] backed by an existing [RandomCoin], so that every crate built on winterfell's
+/// default sponge keeps drawing bit-for-bit identical challenges after adopting [Transcript].
+pub struct RandomCoinTranscript<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    public_coin: R,
+    _marker: PhantomData<(E, H)>,
+}
+
+impl<E, H, R> RandomCoinTranscript<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    pub fn new() -> Self {
+        RandomCoinTranscript {
+            public_coin: RandomCoin::new(&[]),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E, H, R> Transcript<E> for RandomCoinTranscript<E, H, R>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    R: RandomCoin<BaseField = E::BaseField, Hasher = H>,
+{
+    type Hasher = H;
+
+    fn observe_digest(&mut self, digest: H::Digest) {
+        self.public_coin.reseed(digest);
+    }
+
+    fn observe_elements(&mut self, elements: &[E]) {
+        self.public_coin.reseed(H::hash_elements(elements));
+    }
+
+    fn observe_nonce(&mut self, nonce: u64) {
+        self.public_coin.reseed_with_int(nonce);
+    }
+
+    fn check_leading_zeros(&self, nonce: u64) -> u32 {
+        self.public_coin.check_leading_zeros(nonce)
+    }
+
+    fn challenge_field_element(&mut self) -> E {
+        self.public_coin.draw().expect("failed to draw challenge from the transcript")
+    }
+
+    fn challenge_integers(&mut self, count: usize, domain_size: usize) -> Vec<usize> {
+        self.public_coin
+            .draw_integers(count, domain_size, 0)
+            .expect("failed to draw query positions from the transcript")
+    }
+}
+
+// KECCAK256 TRANSCRIPT
+// ================================================================================================
+
+/// A [Transcript] whose challenges a Solidity verifier can reproduce, backed by
+/// [Keccak256RandomCoin](crate::keccak_coin::Keccak256RandomCoin) through [RandomCoinTranscript].
+pub type Keccak256Transcript<E> =
+    RandomCoinTranscript<E, Keccak256_256<<E as FieldElement>::BaseField>, Keccak256RandomCoin<<E as FieldElement>::BaseField>>;
@@ -0,0 +1,44 @@
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, ElementHasher, Hasher, RandomCoin};
+use math::{fields::f128::BaseElement, FieldElement};
+
+use super::{RandomCoinTranscript, Transcript};
+
+type Blake3 = Blake3_256<BaseElement>;
+
+#[test]
+fn test_observe_digest_matches_underlying_random_coin_reseed() {
+    let mut transcript = RandomCoinTranscript::<BaseElement, Blake3, DefaultRandomCoin<Blake3>>::new();
+    let mut reference = DefaultRandomCoin::<Blake3>::new(&[]);
+
+    let digest = Blake3::hash(b"a layer commitment");
+    transcript.observe_digest(digest);
+    reference.reseed(digest);
+
+    let expected: BaseElement = reference.draw().unwrap();
+    assert_eq!(transcript.challenge_field_element(), expected);
+}
+
+#[test]
+fn test_observe_elements_matches_underlying_random_coin_reseed() {
+    let elements = [BaseElement::new(1), BaseElement::new(2), BaseElement::new(3)];
+
+    let mut transcript = RandomCoinTranscript::<BaseElement, Blake3, DefaultRandomCoin<Blake3>>::new();
+    transcript.observe_elements(&elements);
+
+    let mut reference = DefaultRandomCoin::<Blake3>::new(&[]);
+    reference.reseed(Blake3::hash_elements(&elements));
+
+    let expected: BaseElement = reference.draw().unwrap();
+    assert_eq!(transcript.challenge_field_element(), expected);
+}
+
+#[test]
+fn test_challenge_integers_are_in_range() {
+    let mut transcript = RandomCoinTranscript::<BaseElement, Blake3, DefaultRandomCoin<Blake3>>::new();
+    transcript.observe_digest(Blake3::hash(b"seed"));
+
+    let domain_size = 64;
+    let positions = transcript.challenge_integers(20, domain_size);
+    assert_eq!(positions.len(), 20);
+    assert!(positions.iter().all(|&p| p < domain_size));
+}